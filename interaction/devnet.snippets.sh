@@ -53,6 +53,25 @@ upgrade(){
     --send || return
 }
 
+# contracts deployed before the accepted payment tokens registry need the anti spam tax token set at initialization to be registered again
+upgradeRegisteringAntiSpamTaxToken(){
+    # $1 = anti spam tax token identifier
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract upgrade ${ADDRESS} \
+    --bytecode output-docker/datanftmint/datanftmint.wasm \
+    --metadata-payable-by-sc \
+    --metadata-payable \
+    --pem ${WALLET} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --gas-limit 150000000 \
+    --recall-nonce \
+    --arguments $token_identifier \
+    --send || return
+}
+
 # if you interact without calling deploy(), then you need to 1st run this to restore the vars from data
 restoreDeployData() {
   TRANSACTION=$(mxpy data parse --file="./interaction/deploy-devnet.interaction.json" --expression="data['emittedTransactionHash']")
@@ -549,3 +568,733 @@ setDonationTreasuryAddress(){
     --chain ${CHAIN_ID} \
     --send || return
 }
+
+# v5.0.0
+getContractConfig(){
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getContractConfig'
+}
+
+addAcceptedPaymentToken(){
+    # $1 = token identifier
+    # $2 = anti spam tax value
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "addAcceptedPaymentToken" \
+    --arguments $token_identifier ${2} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+removeAcceptedPaymentToken(){
+    # $1 = token identifier
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "removeAcceptedPaymentToken" \
+    --arguments $token_identifier \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getAcceptedPaymentTokens(){
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getAcceptedPaymentTokens'
+}
+
+getMintQuote(){
+    # $1 = caller address
+    # $2 = payment token identifier
+    # $3 = lock period in seconds
+    # $4 = supply
+    # $5 = donation percentage (1% -> 100 ; 100% -> 10000)
+
+    caller="0x$(mxpy wallet bech32 --decode ${1})"
+    token_identifier="0x$(echo -n ${2} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getMintQuote' \
+    --arguments $caller $token_identifier ${3} ${4} ${5}
+}
+
+getErrorCatalog(){
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getErrorCatalog'
+}
+
+getDataNftSupply(){
+    # $1 = nonce
+
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getDataNftSupply' \
+    --arguments ${1}
+}
+
+getConfigVersion(){
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getConfigVersion'
+}
+
+setAuditLogRetention(){
+    # $1 = number of audit log entries to keep (0 keeps all of them)
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setAuditLogRetention" \
+    --arguments ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getAuditLog(){
+    # $1 = id of the first entry
+    # $2 = maximum number of entries
+
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getAuditLog' \
+    --arguments ${1} ${2}
+}
+
+setTreasuryDistribution(){
+    # $1 = first treasury address
+    # $2 = first treasury share in basis points (1% -> 100 ; 100% -> 10000)
+    # $3 = second treasury address
+    # $4 = second treasury share in basis points
+
+    first_address="0x$(mxpy wallet bech32 --decode ${1})"
+    second_address="0x$(mxpy wallet bech32 --decode ${3})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setTreasuryDistribution" \
+    --arguments $first_address ${2} $second_address ${4} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getTreasuryDistribution(){
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getTreasuryDistribution'
+}
+
+setTreasuryAccumulationEnabled(){
+    # $1 = true to accumulate the anti spam tax for the treasuries to claim, false to send it on every mint
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setTreasuryAccumulationEnabled" \
+    --arguments ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+claimTreasury(){
+    # $1 = token identifier
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "claimTreasury" \
+    --arguments $token_identifier \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getTreasuryClaimable(){
+    # $1 = treasury address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getTreasuryClaimable' \
+    --arguments $address
+}
+
+setCreatorRoyaltyShare(){
+    # $1 = creator share of the deposited royalties in basis points (1% -> 100 ; 100% -> 10000)
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setCreatorRoyaltyShare" \
+    --arguments ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+depositRoyalties(){
+    # $1 = nonce
+    # $2 = token identifier
+    # $3 = amount
+
+    method="0x$(echo -n 'depositRoyalties' | xxd -p -u | tr -d '\n')"
+    token_identifier="0x$(echo -n ${2} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "ESDTTransfer" \
+    --arguments $token_identifier ${3} $method ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+claimRoyalties(){
+    # $1 = token identifier
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "claimRoyalties" \
+    --arguments $token_identifier \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getCreatorRoyalties(){
+    # $1 = creator address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getCreatorRoyalties' \
+    --arguments $address
+}
+
+withdrawMany(){
+    # $1 = first token identifier
+    # $2 = first token nonce
+    # $3 = first amount (0 withdraws the whole available balance)
+    # $4 = second token identifier
+    # $5 = second token nonce
+    # $6 = second amount (0 withdraws the whole available balance)
+
+    first_token="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+    second_token="0x$(echo -n ${4} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=10000000 \
+    --function "withdrawMany" \
+    --arguments $first_token ${2} ${3} $second_token ${5} ${6} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+registerRescueClaim(){
+    # $1 = token identifier
+    # $2 = token nonce
+    # $3 = amount
+    # $4 = recipient address
+    # $5 = reason
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+    recipient="0x$(mxpy wallet bech32 --decode ${4})"
+    reason="0x$(echo -n ${5} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "registerRescueClaim" \
+    --arguments $token_identifier ${2} ${3} $recipient $reason \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+cancelRescueClaim(){
+    # $1 = rescue claim id
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "cancelRescueClaim" \
+    --arguments ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+rescueTokens(){
+    # $1 = rescue claim id
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "rescueTokens" \
+    --arguments ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getOpenRescueClaims(){
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getOpenRescueClaims'
+}
+
+addReferrers(){
+    # $1 = referrer address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "addReferrers" \
+    --arguments $address \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+removeReferrers(){
+    # $1 = referrer address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "removeReferrers" \
+    --arguments $address \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+setReferralShare(){
+    # $1 = referrer share of the anti spam tax in basis points (1% -> 100 ; 100% -> 10000)
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setReferralShare" \
+    --arguments ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getReferrerStats(){
+    # $1 = referrer address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getReferrerStats' \
+    --arguments $address
+}
+
+registerPromoCode(){
+    # $1 = promo code
+    # $2 = discount of the anti spam tax in basis points (1% -> 100 ; 100% -> 10000)
+    # $3 = max uses (0 -> unlimited)
+    # $4 = expiry timestamp (0 -> never)
+    # $5 = max uses per address (0 -> unlimited)
+
+    code_hash="0x$(echo -n ${1} | sha256sum | cut -d' ' -f1)"
+    discount="0x00$(printf '%016x' ${2})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "registerPromoCode" \
+    --arguments $code_hash $discount ${3} ${4} ${5} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+removePromoCode(){
+    # $1 = promo code
+
+    code_hash="0x$(echo -n ${1} | sha256sum | cut -d' ' -f1)"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "removePromoCode" \
+    --arguments $code_hash \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+depositSponsorFunds(){
+    # $1 = token identifier
+    # $2 = amount
+
+    method="0x$(echo -n 'depositSponsorFunds' | xxd -p -u | tr -d '\n')"
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "ESDTTransfer" \
+    --arguments $token_identifier ${2} $method \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+withdrawSponsorFunds(){
+    # $1 = amount
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "withdrawSponsorFunds" \
+    --arguments ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+addSponsoredCreator(){
+    # $1 = creator address
+    # $2 = number of sponsored mints (0 -> unlimited)
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "addSponsoredCreators" \
+    --arguments $address ${2} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+acceptSponsor(){
+    # $1 = sponsor address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "acceptSponsor" \
+    --arguments $address \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getSponsorInfo(){
+    # $1 = sponsor address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getSponsorInfo' \
+    --arguments $address
+}
+
+depositCredits(){
+    # $1 = token identifier
+    # $2 = amount
+
+    method="0x$(echo -n 'depositCredits' | xxd -p -u | tr -d '\n')"
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "ESDTTransfer" \
+    --arguments $token_identifier ${2} $method \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+withdrawCredits(){
+    # $1 = token identifier
+    # $2 = amount
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "withdrawCredits" \
+    --arguments $token_identifier ${2} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getCredits(){
+    # $1 = address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getCredits' \
+    --arguments $address
+}
+
+setBondPaymentToken(){
+    # $1 = bond token identifier
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setBondPaymentToken" \
+    --arguments $token_identifier \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+setEgldWrapper(){
+    # $1 = wrapped EGLD token identifier
+    # $2 = egld-esdt-swap contract address
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+    address="0x$(mxpy wallet bech32 --decode ${2})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setEgldWrapper" \
+    --arguments $token_identifier $address \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+setAntiSpamTaxPerUnit(){
+    # $1 = token identifier
+    # $2 = anti spam tax charged for each unit of supply
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setAntiSpamTaxPerUnit" \
+    --arguments $token_identifier ${2} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+setLockPeriodTaxMultiplier(){
+    # $1 = lock period in seconds
+    # $2 = anti spam tax multiplier in basis points (1x -> 10000 ; 0 removes the multiplier)
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setLockPeriodTaxMultiplier" \
+    --arguments ${1} ${2} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+setSurgePricing(){
+    # $1 = sliding window in seconds (0 disables surge pricing)
+    # $2 = mints in the window before the anti spam tax rises
+    # $3 = multiplier increase in basis points for each mint above the threshold
+    # $4 = maximum multiplier in basis points (1x -> 10000)
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setSurgePricing" \
+    --arguments ${1} ${2} ${3} ${4} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+setGlobalMintLimit(){
+    # $1 = maximum number of mints of the whole contract in the window (0 disables the limit)
+    # $2 = rolling window in seconds
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setGlobalMintLimit" \
+    --arguments ${1} ${2} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+addRateLimitExemptions(){
+    # $1 = address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "addRateLimitExemptions" \
+    --arguments $address \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+removeRateLimitExemptions(){
+    # $1 = address
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "removeRateLimitExemptions" \
+    --arguments $address \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+setFreeMintsPerAddress(){
+    # $1 = number of first mints of every address that carry no anti spam tax
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setFreeMintsPerAddress" \
+    --arguments ${1} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+setMintPassToken(){
+    # $1 = mint pass token identifier
+    # $2 = true to burn used mint passes, false to return them to the treasury
+
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "setMintPassToken" \
+    --arguments $token_identifier ${2} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+depositMintPasses(){
+    # $1 = mint pass token identifier
+    # $2 = amount
+
+    method="0x$(echo -n 'depositMintPasses' | xxd -p -u | tr -d '\n')"
+    token_identifier="0x$(echo -n ${1} | xxd -p -u | tr -d '\n')"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "ESDTTransfer" \
+    --arguments $token_identifier ${2} $method \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+distributeMintPasses(){
+    # $1 = address
+    # $2 = amount
+
+    address="0x$(mxpy wallet bech32 --decode ${1})"
+
+    mxpy --verbose contract call ${ADDRESS} \
+    --recall-nonce \
+    --pem=${WALLET} \
+    --gas-limit=6000000 \
+    --function "distributeMintPasses" \
+    --arguments $address ${2} \
+    --proxy ${PROXY} \
+    --chain ${CHAIN_ID} \
+    --send || return
+}
+
+getMintPassConfig(){
+    mxpy --verbose contract query ${ADDRESS} \
+    --proxy ${PROXY} \
+    --function 'getMintPassConfig'
+}
@@ -28,7 +28,6 @@ pub trait BondingContractProxyMethods: crate::storage::StorageModule {
     fn bonding_proxy(&self, sc_address: ManagedAddress)
         -> bonding_contract_proxy::Proxy<Self::Api>;
 
-    #[endpoint]
     fn get_bond_amount_for_lock_period(&self, lock_period: u64) -> BigUint {
         let bonding_contract_address = self.bond_contract_address().get();
         self.bonding_proxy(bonding_contract_address)
@@ -36,7 +35,6 @@ pub trait BondingContractProxyMethods: crate::storage::StorageModule {
             .execute_on_dest_context::<BigUint>()
     }
 
-    #[endpoint]
     fn send_bond(
         &self,
         original_caller: &ManagedAddress,
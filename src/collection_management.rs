@@ -1,6 +1,9 @@
-use crate::errors::{
-    ERR_ADDRESS_NOT_IN_COLLECTION_FREEZE_LIST, ERR_ADDRESS_IS_IN_COLLECTION_FREEZE_LIST,
-    ERR_NONCE_IN_FREEZE_LIST, ERR_NONCE_NOT_FOUND_IN_FREEZE_LIST,
+use crate::{
+    errors::{
+        ERR_ADDRESS_IS_IN_COLLECTION_FREEZE_LIST, ERR_ADDRESS_NOT_IN_COLLECTION_FREEZE_LIST,
+        ERR_NONCE_IN_FREEZE_LIST, ERR_NONCE_NOT_FOUND_IN_FREEZE_LIST,
+    },
+    storage::AuditAction,
 };
 
 multiversx_sc::imports!();
@@ -78,6 +81,7 @@ pub trait CollectionManagement:
     #[endpoint(pause)]
     fn pause_collection(&self) {
         let token_identifier = self.token_id().get_token_id();
+        self.append_audit_entry(AuditAction::PauseCollection, &token_identifier);
         self.pause_collection_event(&token_identifier);
         self.send()
             .esdt_system_sc_proxy()
@@ -91,6 +95,7 @@ pub trait CollectionManagement:
     #[endpoint(unpause)]
     fn unpause_collection(&self) {
         let token_identifier = self.token_id().get_token_id();
+        self.append_audit_entry(AuditAction::UnpauseCollection, &token_identifier);
         self.unpause_collection_event(&token_identifier);
         self.send()
             .esdt_system_sc_proxy()
@@ -108,6 +113,7 @@ pub trait CollectionManagement:
             .insert(address.clone())
         {
             let token_identifier = self.token_id().get_token_id();
+            self.append_audit_entry(AuditAction::FreezeCollection, address);
             self.set_collection_freeze_list_spot_event(&address);
             self.send()
                 .esdt_system_sc_proxy()
@@ -125,6 +131,7 @@ pub trait CollectionManagement:
     fn unfreeze_collection_for_address(&self, address: &ManagedAddress) {
         if self.frozen_addresses_for_collection().remove(address) {
             let token_identifier = self.token_id().get_token_id();
+            self.append_audit_entry(AuditAction::UnfreezeCollection, address);
             self.remove_collection_freeze_list_spot_event(&address);
             self.send()
                 .esdt_system_sc_proxy()
@@ -144,6 +151,7 @@ pub trait CollectionManagement:
         if self.frozen_sfts_per_address(&address).insert(nonce) {
             let total_frozen = self.frozen_sfts_per_address(&address).len();
             self.frozen_count(&address).set(&total_frozen);
+            self.append_audit_entry(AuditAction::FreezeSingleNft, &(nonce, address.clone()));
             self.set_frozen_sfts_per_address_event(&address, nonce);
             self.freeze_single_nft(nonce, &address)
                 .async_call()
@@ -161,6 +169,7 @@ pub trait CollectionManagement:
         if self.frozen_sfts_per_address(&address).remove(&nonce) {
             let total_frozen = self.frozen_sfts_per_address(&address).len();
             self.frozen_count(&address).set(&total_frozen);
+            self.append_audit_entry(AuditAction::UnfreezeSingleNft, &(nonce, address.clone()));
             self.remove_frozen_sfts_per_address_event(&address, nonce);
             self.unfreeze_single_nft(nonce, &address)
                 .async_call()
@@ -179,6 +188,7 @@ pub trait CollectionManagement:
         if self.frozen_sfts_per_address(&address).remove(&nonce) {
             let total_frozen = self.frozen_sfts_per_address(&address).len();
             self.frozen_count(&address).set(&total_frozen);
+            self.append_audit_entry(AuditAction::WipeSingleNft, &(nonce, address.clone()));
             self.wipe_event(&address, &token_identifier, nonce);
             self.wipe_single_nft(nonce, &address)
                 .async_call()
@@ -0,0 +1,110 @@
+use crate::errors::ERR_CREDIT_BALANCE_TOO_LOW;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+// Module that handles prepaid mint credits, debited for the anti spam tax and bond of mints sent without payment
+#[multiversx_sc::module]
+pub trait CreditsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::requirements::RequirementsModule
+    + crate::nft_mint_utils::NftMintUtils
+    + crate::egld_wrapper_proxy::EgldWrapperProxyMethods
+{
+    // Endpoint used by a creator to top up its mint credits in an accepted payment token
+    #[payable("*")]
+    #[endpoint(depositCredits)]
+    fn deposit_credits(&self) {
+        let caller = self.blockchain().get_caller();
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_payment_token_is_accepted(&self.get_anti_spam_tax_token(&token));
+        self.require_value_is_positive(&amount);
+
+        self.credit_balance(&caller, &token)
+            .update(|balance| *balance += &amount);
+        self.credit_tokens(&caller).insert(token.clone());
+        self.reserved_funds(&token, 0)
+            .update(|reserved| *reserved += &amount);
+        self.credits_deposited_event(&caller, &token, &amount);
+    }
+
+    // Endpoint used by a creator to withdraw part of its mint credits
+    #[endpoint(withdrawCredits)]
+    fn withdraw_credits(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.require_value_is_positive(&amount);
+        self.debit_credits(&caller, &token, &amount);
+
+        self.send().direct(&caller, &token, 0, &amount);
+        self.credits_withdrawn_event(&caller, &token, &amount);
+    }
+
+    // Returns the first token in which the credits of the creator cover the anti spam tax and the bond, wrapped EGLD credits being taxed as EGLD
+    fn get_credit_payment_token(
+        &self,
+        creator: &ManagedAddress,
+        bond_amount: &BigUint,
+        supply: &BigUint,
+        lock_period_sec: u64,
+    ) -> EgldOrEsdtTokenIdentifier {
+        for token in self.credit_tokens(creator).iter() {
+            let tax_token = self.get_anti_spam_tax_token(&token);
+            if !self.accepted_payment_tokens().contains(&tax_token) {
+                continue;
+            }
+            let total_payment =
+                &self.compute_anti_spam_tax(&tax_token, supply, lock_period_sec) + bond_amount;
+            if self.credit_balance(creator, &token).get() >= total_payment {
+                return token;
+            }
+        }
+        sc_panic!(ERR_CREDIT_BALANCE_TOO_LOW);
+    }
+
+    // Debits the payment of a mint from the credits of its creator
+    fn consume_credits(
+        &self,
+        creator: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.debit_credits(creator, token, amount);
+        self.credits_used_event(creator, token, amount);
+    }
+
+    // Removes an amount from the credits of an address and releases it from the reserved funds
+    fn debit_credits(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let balance = self.credit_balance(address, token).get();
+        require!(amount <= &balance, ERR_CREDIT_BALANCE_TOO_LOW);
+
+        let remaining = balance - amount;
+        if remaining == 0 {
+            self.credit_balance(address, token).clear();
+            self.credit_tokens(address).swap_remove(token);
+        } else {
+            self.credit_balance(address, token).set(&remaining);
+        }
+        self.reserved_funds(token, 0)
+            .update(|reserved| *reserved -= amount);
+    }
+
+    // View that returns the mint credits of an address in every token
+    #[view(getCredits)]
+    fn get_credits(
+        &self,
+        address: &ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut credits = MultiValueEncoded::new();
+        for token in self.credit_tokens(address).iter() {
+            let balance = self.credit_balance(address, &token).get();
+            credits.push(MultiValue2((token, balance)));
+        }
+        credits
+    }
+}
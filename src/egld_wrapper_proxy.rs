@@ -0,0 +1,51 @@
+multiversx_sc::imports!();
+
+mod egld_wrapper_contract_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait EgldWrapperProxy {
+        #[payable("*")]
+        #[endpoint(unwrapEgld)]
+        fn unwrap_egld(&self);
+    }
+}
+
+// Module that handles unwrapping wrapped EGLD payments through the egld-esdt-swap contract
+#[multiversx_sc::module]
+pub trait EgldWrapperProxyMethods: crate::storage::StorageModule {
+    #[proxy]
+    fn egld_wrapper_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> egld_wrapper_contract_proxy::Proxy<Self::Api>;
+
+    // Checks whether a token is the wrapped EGLD token and a swap contract is set to unwrap it
+    fn is_wrapped_egld(&self, token: &EgldOrEsdtTokenIdentifier) -> bool {
+        !self.wrapped_egld_token_id().is_empty()
+            && !self.egld_wrapper_address().is_empty()
+            && token == &EgldOrEsdtTokenIdentifier::esdt(self.wrapped_egld_token_id().get())
+    }
+
+    // Returns the token the anti spam tax of a payment is priced in, wrapped EGLD being priced as EGLD
+    fn get_anti_spam_tax_token(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> EgldOrEsdtTokenIdentifier {
+        if self.is_wrapped_egld(token) {
+            EgldOrEsdtTokenIdentifier::egld()
+        } else {
+            token.clone()
+        }
+    }
+
+    // Unwraps a wrapped EGLD payment, returning the EGLD payment received in exchange
+    fn unwrap_egld_payment(&self, payment: EgldOrEsdtTokenPayment) -> EgldOrEsdtTokenPayment {
+        let amount = payment.amount.clone();
+        self.egld_wrapper_proxy(self.egld_wrapper_address().get())
+            .unwrap_egld()
+            .with_esdt_transfer(payment.unwrap_esdt())
+            .execute_on_dest_context::<()>();
+        EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, amount)
+    }
+}
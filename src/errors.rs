@@ -1,41 +1,357 @@
-pub const ERR_MIN_ROYALTIES_BIGGER_THAN_MAX_ROYALTIES: &str =
-    "Min royalties bigger than max royalties";
-pub const ERR_MAX_ROYALTIES_TOO_HIGH: &str = "Max royalties too high";
-pub const ERR_TOKEN_NOT_ISSUED: &str = "Token not issued";
-pub const ERR_URL_TOO_SMALL: &str = "URL length is too small";
-pub const ERR_URL_TOO_BIG: &str = "URL length is too big";
-pub const ERR_URL_IS_EMPTY: &str = "URL is empty";
-pub const ERR_URL_INVALID_CHARACTERS: &str = "URL contains invalid characters";
-pub const ERR_NOT_URL: &str = "URL must start with https://";
-pub const ERR_NOT_PRIVILEGED: &str = "Address is not privileged";
-pub const ERR_SUPPLY_HIGHER_THAN_ZERO: &str = "Supply must be higher than zero";
-pub const ERR_MAX_SUPPLY_EXCEEDED: &str = "Max supply exceeded";
-pub const ERR_ROYALTIES_ARE_SMALLER_THAN_MIN_ROYALTIES: &str =
-    "Royalties are smaller than min royalties";
+// Every error the smart contract can fail with, identified by a stable numeric code
+// Codes are grouped by area and must never be changed or reused, integrators match on them instead of the message wording
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum MinterError {
+    // Mint arguments
+    FieldIsEmpty = 1001,
+    TooManyChars = 1002,
+    DataStreamIsEmpty = 1003,
+    UrlIsEmpty = 1004,
+    NotUrl = 1005,
+    UrlTooBig = 1006,
+    UrlTooSmall = 1007,
+    UrlInvalidCharacters = 1008,
+    RoyaltiesAreBiggerThanMaxRoyalties = 1009,
+    RoyaltiesAreSmallerThanMinRoyalties = 1010,
+    MaxSupplyExceeded = 1011,
+    SupplyHigherThanZero = 1012,
+    PercentageTooHigh = 1013,
+    ValueMustBePositive = 1014,
+
+    // Minting state and permissions
+    MintingAndBurningNotAllowed = 2001,
+    NotWhitelisted = 2002,
+    WaitMoreTime = 2003,
+    GlobalMintLimitReached = 2004,
+    InvalidMintPass = 2005,
+
+    // Payments
+    WrongAmountOfFunds = 3001,
+    WrongAmountOfPayment = 3002,
+    WrongBondPeriod = 3003,
+    PaymentTokenNotAccepted = 3004,
+    IssueCost = 3005,
+    PromoCodeNotFound = 3006,
+    PromoCodeExpired = 3007,
+    PromoCodeUsageCapReached = 3008,
+    PromoCodeAddressLimitReached = 3009,
+    PromoCodeNotValidForToken = 3010,
+    SponsorBalanceTooLow = 3011,
+    SponsoredMintLimitReached = 3012,
+    SponsorTokenMismatch = 3013,
+    CreditBalanceTooLow = 3014,
+    MintPriceAboveMaxPayment = 3015,
+    WrongBondPaymentToken = 3016,
+    BondPaymentTokenNotSet = 3017,
+    NotEnoughMintPasses = 3018,
+
+    // Administration
+    NotPrivileged = 4001,
+    MinRoyaltiesBiggerThanMaxRoyalties = 4002,
+    MaxRoyaltiesTooHigh = 4003,
+    WhitelistIsEmpty = 4004,
+    AlreadyInWhitelist = 4005,
+    NotInWhitelist = 4006,
+    PaymentTokenAlreadyAccepted = 4007,
+    ContractAlreadyInitialized = 4008,
+    TokenIssued = 4009,
+    TokenNotIssued = 4010,
+    InvalidTreasuryDistribution = 4011,
+    TooManyTreasuryShares = 4012,
+    ReferrerAlreadyRegistered = 4013,
+    ReferrerNotRegistered = 4014,
+    PromoCodeAlreadyRegistered = 4015,
+    CreatorAlreadySponsored = 4016,
+    CreatorNotSponsored = 4017,
+    InvalidSurgePricing = 4018,
+    InvalidGlobalMintLimit = 4019,
+    MintPassTokenNotSet = 4020,
+    InvalidMintPassToken = 4021,
+    SponsorshipNotOffered = 4022,
+    MissingMintPassBurnRole = 4023,
+    MintPassesStillReserved = 4024,
+
+    // Collection management
+    NonceNotFoundInFreezeList = 5001,
+    NonceInFreezeList = 5002,
+    AddressNotInCollectionFreezeList = 5003,
+    AddressIsInCollectionFreezeList = 5004,
+
+    // Withdrawals
+    WithdrawalAddressNotSet = 6001,
+    OnlyWithdrawalAddressCanWithdraw = 6002,
+    NothingToClaim = 6003,
+    RescueClaimNotFound = 6004,
+    NotRescueRecipient = 6005,
+    DataNftCreatorNotFound = 6006,
+}
+
+impl MinterError {
+    pub const ALL: &'static [MinterError] = &[
+        MinterError::FieldIsEmpty,
+        MinterError::TooManyChars,
+        MinterError::DataStreamIsEmpty,
+        MinterError::UrlIsEmpty,
+        MinterError::NotUrl,
+        MinterError::UrlTooBig,
+        MinterError::UrlTooSmall,
+        MinterError::UrlInvalidCharacters,
+        MinterError::RoyaltiesAreBiggerThanMaxRoyalties,
+        MinterError::RoyaltiesAreSmallerThanMinRoyalties,
+        MinterError::MaxSupplyExceeded,
+        MinterError::SupplyHigherThanZero,
+        MinterError::PercentageTooHigh,
+        MinterError::ValueMustBePositive,
+        MinterError::MintingAndBurningNotAllowed,
+        MinterError::NotWhitelisted,
+        MinterError::WaitMoreTime,
+        MinterError::GlobalMintLimitReached,
+        MinterError::InvalidMintPass,
+        MinterError::WrongAmountOfFunds,
+        MinterError::WrongAmountOfPayment,
+        MinterError::WrongBondPeriod,
+        MinterError::PaymentTokenNotAccepted,
+        MinterError::IssueCost,
+        MinterError::PromoCodeNotFound,
+        MinterError::PromoCodeExpired,
+        MinterError::PromoCodeUsageCapReached,
+        MinterError::PromoCodeAddressLimitReached,
+        MinterError::PromoCodeNotValidForToken,
+        MinterError::SponsorBalanceTooLow,
+        MinterError::SponsoredMintLimitReached,
+        MinterError::SponsorTokenMismatch,
+        MinterError::CreditBalanceTooLow,
+        MinterError::MintPriceAboveMaxPayment,
+        MinterError::WrongBondPaymentToken,
+        MinterError::BondPaymentTokenNotSet,
+        MinterError::NotEnoughMintPasses,
+        MinterError::NotPrivileged,
+        MinterError::MinRoyaltiesBiggerThanMaxRoyalties,
+        MinterError::MaxRoyaltiesTooHigh,
+        MinterError::WhitelistIsEmpty,
+        MinterError::AlreadyInWhitelist,
+        MinterError::NotInWhitelist,
+        MinterError::PaymentTokenAlreadyAccepted,
+        MinterError::ContractAlreadyInitialized,
+        MinterError::TokenIssued,
+        MinterError::TokenNotIssued,
+        MinterError::InvalidTreasuryDistribution,
+        MinterError::TooManyTreasuryShares,
+        MinterError::ReferrerAlreadyRegistered,
+        MinterError::ReferrerNotRegistered,
+        MinterError::PromoCodeAlreadyRegistered,
+        MinterError::CreatorAlreadySponsored,
+        MinterError::CreatorNotSponsored,
+        MinterError::InvalidSurgePricing,
+        MinterError::InvalidGlobalMintLimit,
+        MinterError::MintPassTokenNotSet,
+        MinterError::InvalidMintPassToken,
+        MinterError::SponsorshipNotOffered,
+        MinterError::MissingMintPassBurnRole,
+        MinterError::MintPassesStillReserved,
+        MinterError::NonceNotFoundInFreezeList,
+        MinterError::NonceInFreezeList,
+        MinterError::AddressNotInCollectionFreezeList,
+        MinterError::AddressIsInCollectionFreezeList,
+        MinterError::WithdrawalAddressNotSet,
+        MinterError::OnlyWithdrawalAddressCanWithdraw,
+        MinterError::NothingToClaim,
+        MinterError::RescueClaimNotFound,
+        MinterError::NotRescueRecipient,
+        MinterError::DataNftCreatorNotFound,
+    ];
+
+    pub const fn code(self) -> u32 {
+        self as u32
+    }
+
+    // Returns the message signaled on failure, prefixed with the error code (e.g. "E1007: URL length is too small")
+    pub const fn message(self) -> &'static str {
+        match self {
+            MinterError::FieldIsEmpty => "E1001: Field is empty",
+            MinterError::TooManyChars => "E1002: Too many characters",
+            MinterError::DataStreamIsEmpty => "E1003: Data Stream is empty",
+            MinterError::UrlIsEmpty => "E1004: URL is empty",
+            MinterError::NotUrl => "E1005: URL must start with https://",
+            MinterError::UrlTooBig => "E1006: URL length is too big",
+            MinterError::UrlTooSmall => "E1007: URL length is too small",
+            MinterError::UrlInvalidCharacters => "E1008: URL contains invalid characters",
+            MinterError::RoyaltiesAreBiggerThanMaxRoyalties => {
+                "E1009: Royalties are bigger than max royalties"
+            }
+            MinterError::RoyaltiesAreSmallerThanMinRoyalties => {
+                "E1010: Royalties are smaller than min royalties"
+            }
+            MinterError::MaxSupplyExceeded => "E1011: Max supply exceeded",
+            MinterError::SupplyHigherThanZero => "E1012: Supply must be higher than zero",
+            MinterError::PercentageTooHigh => "E1013: Percentage too high",
+            MinterError::ValueMustBePositive => "E1014: Value must be higher than zero",
+            MinterError::MintingAndBurningNotAllowed => "E2001: Minting and burning not allowed",
+            MinterError::NotWhitelisted => "E2002: You are not whitelisted",
+            MinterError::WaitMoreTime => "E2003: You need to wait more time before minting again",
+            MinterError::GlobalMintLimitReached => {
+                "E2004: Global mint limit reached, try again later"
+            }
+            MinterError::InvalidMintPass => "E2005: Invalid mint pass",
+            MinterError::WrongAmountOfFunds => "E3001: Wrong amount of funds",
+            MinterError::WrongAmountOfPayment => "E3002: Wrong amount of payment sent",
+            MinterError::WrongBondPeriod => "E3003: Wrong bond period",
+            MinterError::PaymentTokenNotAccepted => "E3004: Payment token not accepted",
+            MinterError::IssueCost => "E3005: Issue cost is 0.05 eGLD",
+            MinterError::PromoCodeNotFound => "E3006: Promo code not found",
+            MinterError::PromoCodeExpired => "E3007: Promo code expired",
+            MinterError::PromoCodeUsageCapReached => "E3008: Promo code usage cap reached",
+            MinterError::PromoCodeAddressLimitReached => {
+                "E3009: Promo code limit reached for address"
+            }
+            MinterError::PromoCodeNotValidForToken => {
+                "E3010: Promo code not valid for payment token"
+            }
+            MinterError::SponsorBalanceTooLow => "E3011: Sponsor balance too low",
+            MinterError::SponsoredMintLimitReached => "E3012: Sponsored mint limit reached",
+            MinterError::SponsorTokenMismatch => {
+                "E3013: Sponsor funds must be deposited in the same token"
+            }
+            MinterError::CreditBalanceTooLow => "E3014: Credit balance too low",
+            MinterError::MintPriceAboveMaxPayment => "E3015: Mint price exceeds maximum payment",
+            MinterError::WrongBondPaymentToken => "E3016: Wrong bond payment token",
+            MinterError::BondPaymentTokenNotSet => "E3017: Bond payment token not set",
+            MinterError::NotEnoughMintPasses => "E3018: Not enough mint passes deposited",
+            MinterError::NotPrivileged => "E4001: Address is not privileged",
+            MinterError::MinRoyaltiesBiggerThanMaxRoyalties => {
+                "E4002: Min royalties bigger than max royalties"
+            }
+            MinterError::MaxRoyaltiesTooHigh => "E4003: Max royalties too high",
+            MinterError::WhitelistIsEmpty => "E4004: Given whitelist is empty",
+            MinterError::AlreadyInWhitelist => "E4005: Address already in whitelist",
+            MinterError::NotInWhitelist => "E4006: Address not in whitelist",
+            MinterError::PaymentTokenAlreadyAccepted => "E4007: Payment token already accepted",
+            MinterError::ContractAlreadyInitialized => "E4008: Contract was already initialized",
+            MinterError::TokenIssued => "E4009: Token already issued",
+            MinterError::TokenNotIssued => "E4010: Token not issued",
+            MinterError::InvalidTreasuryDistribution => {
+                "E4011: Treasury distribution must sum to 10000 basis points"
+            }
+            MinterError::TooManyTreasuryShares => "E4012: Too many treasury shares",
+            MinterError::ReferrerAlreadyRegistered => "E4013: Referrer already registered",
+            MinterError::ReferrerNotRegistered => "E4014: Referrer not registered",
+            MinterError::PromoCodeAlreadyRegistered => "E4015: Promo code already registered",
+            MinterError::CreatorAlreadySponsored => "E4016: Creator already sponsored",
+            MinterError::CreatorNotSponsored => "E4017: Creator not sponsored by caller",
+            MinterError::InvalidSurgePricing => "E4018: Invalid surge pricing",
+            MinterError::InvalidGlobalMintLimit => "E4019: Invalid global mint limit",
+            MinterError::MintPassTokenNotSet => "E4020: Mint pass token not set",
+            MinterError::InvalidMintPassToken => "E4021: Invalid mint pass token",
+            MinterError::SponsorshipNotOffered => "E4022: Sponsorship not offered",
+            MinterError::MissingMintPassBurnRole => "E4023: Missing mint pass burn role",
+            MinterError::MintPassesStillReserved => {
+                "E4024: Mint passes of the current token are still reserved"
+            }
+            MinterError::NonceNotFoundInFreezeList => "E5001: Nonce not found in freeze list",
+            MinterError::NonceInFreezeList => "E5002: Nonce is in freeze list",
+            MinterError::AddressNotInCollectionFreezeList => {
+                "E5003: Address is not in collection freeze list"
+            }
+            MinterError::AddressIsInCollectionFreezeList => {
+                "E5004: Address is in collection freeze list"
+            }
+            MinterError::WithdrawalAddressNotSet => "E6001: Withdrawal address not set",
+            MinterError::OnlyWithdrawalAddressCanWithdraw => {
+                "E6002: Only withdrawal address can withdraw tokens"
+            }
+            MinterError::NothingToClaim => "E6003: Nothing to claim",
+            MinterError::RescueClaimNotFound => "E6004: Rescue claim not found",
+            MinterError::NotRescueRecipient => {
+                "E6005: Only the recipient can confirm the rescue claim"
+            }
+            MinterError::DataNftCreatorNotFound => "E6006: Data NFT creator not found",
+        }
+    }
+}
+
+pub const ERR_FIELD_IS_EMPTY: &str = MinterError::FieldIsEmpty.message();
+pub const ERR_TOO_MANY_CHARS: &str = MinterError::TooManyChars.message();
+pub const ERR_DATA_STREAM_IS_EMPTY: &str = MinterError::DataStreamIsEmpty.message();
+pub const ERR_URL_IS_EMPTY: &str = MinterError::UrlIsEmpty.message();
+pub const ERR_NOT_URL: &str = MinterError::NotUrl.message();
+pub const ERR_URL_TOO_BIG: &str = MinterError::UrlTooBig.message();
+pub const ERR_URL_TOO_SMALL: &str = MinterError::UrlTooSmall.message();
+pub const ERR_URL_INVALID_CHARACTERS: &str = MinterError::UrlInvalidCharacters.message();
 pub const ERR_ROYALTIES_ARE_BIGGER_THAN_MAX_ROYALTIES: &str =
-    "Royalties are bigger than max royalties";
-pub const ERR_VALUE_MUST_BE_POSITIVE: &str = "Value must be higher than zero";
-pub const ERR_NOT_WHITELISTED: &str = "You are not whitelisted";
-pub const ERR_NOT_IN_WHITELIST: &str = "Address not in whitelist";
-pub const ERR_ALREADY_IN_WHITELIST: &str = "Address already in whitelist";
-pub const ERR_WAIT_MORE_TIME: &str = "You need to wait more time before minting again";
-pub const ERR_MINTING_AND_BURNING_NOT_ALLOWED: &str = "Minting and burning not allowed";
-pub const ERR_CONTRACT_ALREADY_INITIALIZED: &str = "Contract was already initialized";
-pub const ERR_ISSUE_COST: &str = "Issue cost is 0.05 eGLD";
-pub const ERR_WRONG_AMOUNT_OF_PAYMENT: &str = "Wrong amount of payment sent";
-pub const ERR_WHITELIST_IS_EMPTY: &str = "Given whitelist is empty";
-pub const ERR_DATA_STREAM_IS_EMPTY: &str = "Data Stream is empty";
-pub const ERR_TOKEN_ISSUED: &str = "Token already issued";
-pub const ERR_NONCE_NOT_FOUND_IN_FREEZE_LIST: &str = "Nonce not found in freeze list";
-pub const ERR_NONCE_IN_FREEZE_LIST: &str = "Nonce is in freeze list";
+    MinterError::RoyaltiesAreBiggerThanMaxRoyalties.message();
+pub const ERR_ROYALTIES_ARE_SMALLER_THAN_MIN_ROYALTIES: &str =
+    MinterError::RoyaltiesAreSmallerThanMinRoyalties.message();
+pub const ERR_MAX_SUPPLY_EXCEEDED: &str = MinterError::MaxSupplyExceeded.message();
+pub const ERR_SUPPLY_HIGHER_THAN_ZERO: &str = MinterError::SupplyHigherThanZero.message();
+pub const ERR_PERCENTAGE_TOO_HIGH: &str = MinterError::PercentageTooHigh.message();
+pub const ERR_VALUE_MUST_BE_POSITIVE: &str = MinterError::ValueMustBePositive.message();
+pub const ERR_MINTING_AND_BURNING_NOT_ALLOWED: &str =
+    MinterError::MintingAndBurningNotAllowed.message();
+pub const ERR_NOT_WHITELISTED: &str = MinterError::NotWhitelisted.message();
+pub const ERR_WAIT_MORE_TIME: &str = MinterError::WaitMoreTime.message();
+pub const ERR_GLOBAL_MINT_LIMIT_REACHED: &str = MinterError::GlobalMintLimitReached.message();
+pub const ERR_INVALID_MINT_PASS: &str = MinterError::InvalidMintPass.message();
+pub const ERR_WRONG_AMOUNT_OF_FUNDS: &str = MinterError::WrongAmountOfFunds.message();
+pub const ERR_WRONG_AMOUNT_OF_PAYMENT: &str = MinterError::WrongAmountOfPayment.message();
+pub const ERR_WRONG_BOND_PERIOD: &str = MinterError::WrongBondPeriod.message();
+pub const ERR_PAYMENT_TOKEN_NOT_ACCEPTED: &str = MinterError::PaymentTokenNotAccepted.message();
+pub const ERR_ISSUE_COST: &str = MinterError::IssueCost.message();
+pub const ERR_PROMO_CODE_NOT_FOUND: &str = MinterError::PromoCodeNotFound.message();
+pub const ERR_PROMO_CODE_EXPIRED: &str = MinterError::PromoCodeExpired.message();
+pub const ERR_PROMO_CODE_USAGE_CAP_REACHED: &str = MinterError::PromoCodeUsageCapReached.message();
+pub const ERR_PROMO_CODE_ADDRESS_LIMIT_REACHED: &str =
+    MinterError::PromoCodeAddressLimitReached.message();
+pub const ERR_PROMO_CODE_NOT_VALID_FOR_TOKEN: &str =
+    MinterError::PromoCodeNotValidForToken.message();
+pub const ERR_SPONSOR_BALANCE_TOO_LOW: &str = MinterError::SponsorBalanceTooLow.message();
+pub const ERR_SPONSORED_MINT_LIMIT_REACHED: &str = MinterError::SponsoredMintLimitReached.message();
+pub const ERR_SPONSOR_TOKEN_MISMATCH: &str = MinterError::SponsorTokenMismatch.message();
+pub const ERR_CREDIT_BALANCE_TOO_LOW: &str = MinterError::CreditBalanceTooLow.message();
+pub const ERR_MINT_PRICE_ABOVE_MAX_PAYMENT: &str = MinterError::MintPriceAboveMaxPayment.message();
+pub const ERR_WRONG_BOND_PAYMENT_TOKEN: &str = MinterError::WrongBondPaymentToken.message();
+pub const ERR_BOND_PAYMENT_TOKEN_NOT_SET: &str = MinterError::BondPaymentTokenNotSet.message();
+pub const ERR_NOT_ENOUGH_MINT_PASSES: &str = MinterError::NotEnoughMintPasses.message();
+pub const ERR_NOT_PRIVILEGED: &str = MinterError::NotPrivileged.message();
+pub const ERR_MIN_ROYALTIES_BIGGER_THAN_MAX_ROYALTIES: &str =
+    MinterError::MinRoyaltiesBiggerThanMaxRoyalties.message();
+pub const ERR_MAX_ROYALTIES_TOO_HIGH: &str = MinterError::MaxRoyaltiesTooHigh.message();
+pub const ERR_WHITELIST_IS_EMPTY: &str = MinterError::WhitelistIsEmpty.message();
+pub const ERR_ALREADY_IN_WHITELIST: &str = MinterError::AlreadyInWhitelist.message();
+pub const ERR_NOT_IN_WHITELIST: &str = MinterError::NotInWhitelist.message();
+pub const ERR_PAYMENT_TOKEN_ALREADY_ACCEPTED: &str =
+    MinterError::PaymentTokenAlreadyAccepted.message();
+pub const ERR_CONTRACT_ALREADY_INITIALIZED: &str =
+    MinterError::ContractAlreadyInitialized.message();
+pub const ERR_TOKEN_ISSUED: &str = MinterError::TokenIssued.message();
+pub const ERR_TOKEN_NOT_ISSUED: &str = MinterError::TokenNotIssued.message();
+pub const ERR_INVALID_TREASURY_DISTRIBUTION: &str =
+    MinterError::InvalidTreasuryDistribution.message();
+pub const ERR_TOO_MANY_TREASURY_SHARES: &str = MinterError::TooManyTreasuryShares.message();
+pub const ERR_REFERRER_ALREADY_REGISTERED: &str = MinterError::ReferrerAlreadyRegistered.message();
+pub const ERR_REFERRER_NOT_REGISTERED: &str = MinterError::ReferrerNotRegistered.message();
+pub const ERR_PROMO_CODE_ALREADY_REGISTERED: &str =
+    MinterError::PromoCodeAlreadyRegistered.message();
+pub const ERR_CREATOR_ALREADY_SPONSORED: &str = MinterError::CreatorAlreadySponsored.message();
+pub const ERR_CREATOR_NOT_SPONSORED: &str = MinterError::CreatorNotSponsored.message();
+pub const ERR_INVALID_SURGE_PRICING: &str = MinterError::InvalidSurgePricing.message();
+pub const ERR_INVALID_GLOBAL_MINT_LIMIT: &str = MinterError::InvalidGlobalMintLimit.message();
+pub const ERR_MINT_PASS_TOKEN_NOT_SET: &str = MinterError::MintPassTokenNotSet.message();
+pub const ERR_INVALID_MINT_PASS_TOKEN: &str = MinterError::InvalidMintPassToken.message();
+pub const ERR_SPONSORSHIP_NOT_OFFERED: &str = MinterError::SponsorshipNotOffered.message();
+pub const ERR_MISSING_MINT_PASS_BURN_ROLE: &str = MinterError::MissingMintPassBurnRole.message();
+pub const ERR_MINT_PASSES_STILL_RESERVED: &str = MinterError::MintPassesStillReserved.message();
+pub const ERR_NONCE_NOT_FOUND_IN_FREEZE_LIST: &str =
+    MinterError::NonceNotFoundInFreezeList.message();
+pub const ERR_NONCE_IN_FREEZE_LIST: &str = MinterError::NonceInFreezeList.message();
 pub const ERR_ADDRESS_NOT_IN_COLLECTION_FREEZE_LIST: &str =
-    "Address is not in collection freeze list";
-pub const ERR_ADDRESS_IS_IN_COLLECTION_FREEZE_LIST: &str = "Address is in collection freeze list";
-pub const ERR_FIELD_IS_EMPTY: &str = "Field is empty";
-pub const ERR_TOO_MANY_CHARS: &str = "Too many characters";
+    MinterError::AddressNotInCollectionFreezeList.message();
+pub const ERR_ADDRESS_IS_IN_COLLECTION_FREEZE_LIST: &str =
+    MinterError::AddressIsInCollectionFreezeList.message();
+pub const ERR_WITHDRAWAL_ADDRESS_NOT_SET: &str = MinterError::WithdrawalAddressNotSet.message();
 pub const ERR_ONLY_WITHDRAWAL_ADDRESS_CAN_WITHDRAW: &str =
-    "Only withdrawal address can withdraw tokens";
-pub const ERR_WITHDRAWAL_ADDRESS_NOT_SET: &str = "Withdrawal address not set";
-pub const ERR_WRONG_AMOUNT_OF_FUNDS: &str = "Wrong amount of funds";
-pub const ERR_WRONG_BOND_PERIOD: &str = "Wrong bond period";
-pub const ERR_PERCENTAGE_TOO_HIGH: &str = "Percentage too high";
+    MinterError::OnlyWithdrawalAddressCanWithdraw.message();
+pub const ERR_NOTHING_TO_CLAIM: &str = MinterError::NothingToClaim.message();
+pub const ERR_RESCUE_CLAIM_NOT_FOUND: &str = MinterError::RescueClaimNotFound.message();
+pub const ERR_NOT_RESCUE_RECIPIENT: &str = MinterError::NotRescueRecipient.message();
+pub const ERR_DATA_NFT_CREATOR_NOT_FOUND: &str = MinterError::DataNftCreatorNotFound.message();
@@ -1,35 +1,237 @@
+use crate::storage::{PromoCode, RescueClaim};
+
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
+
+// Layout versions of the mint and burn events, increased whenever their fields change
+pub const MINT_EVENT_VERSION: u32 = 4;
+pub const BURN_EVENT_VERSION: u32 = 1;
+
+// Complete record of a mint, logged as the data of the mint event
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct MintEventData<M: ManagedTypeApi> {
+    pub supply: BigUint<M>,
+    pub royalties: BigUint<M>,
+    pub lock_period_sec: u64,
+    pub payment_token: EgldOrEsdtTokenIdentifier<M>,
+    pub anti_spam_tax: BigUint<M>,
+    pub bond_token: EgldOrEsdtTokenIdentifier<M>,
+    pub bond_amount: BigUint<M>,
+    pub refund: BigUint<M>,
+    pub free_mint: bool,
+    pub donation_supply: BigUint<M>,
+    pub creator_supply: BigUint<M>,
+    pub data_hash: ManagedBuffer<M>,
+    pub extra_assets: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+// Old and new value of a setting, top-encoded, logged as the data of the configChanged event
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct ConfigChange<M: ManagedTypeApi> {
+    pub old_value: ManagedBuffer<M>,
+    pub new_value: ManagedBuffer<M>,
+}
+
 // Module that handles event emitting for important smart contract events in order to facilitate logging, debugging and monitoring with ease
 #[multiversx_sc::module]
-pub trait EventsModule {
-    // Emitted whenever minting pause changes value
-    #[event("mintPauseToggle")]
-    fn mint_pause_toggle_event(&self, #[indexed] pause_value: &bool);
+pub trait EventsModule: crate::storage::StorageModule {
+    // Increases the config version and emits the configChanged event for a setting
+    fn emit_config_changed<T: TopEncode>(&self, setting: &[u8], old_value: &T, new_value: &T) {
+        let config_version = self.config_version().update(|version| {
+            *version += 1;
+            *version
+        });
+        let change = ConfigChange {
+            old_value: self.encode_value(old_value),
+            new_value: self.encode_value(new_value),
+        };
+        self.config_changed_event(
+            &ManagedBuffer::from(setting),
+            &self.blockchain().get_caller(),
+            config_version,
+            &change,
+        );
+    }
 
-    // Emitted whenever treasury address is set
-    #[event("setTreasuryAddress")]
-    fn treasury_address_event(&self, #[indexed] treasury_address: &ManagedAddress);
-
-    // Emitted whenever donation treasury address is set
-    #[event("setDonationTreasuryAddress")]
-    fn donation_treasury_address_event(
+    // Emitted whenever a setting of the contract changes value
+    #[event("configChanged")]
+    fn config_changed_event(
         &self,
-        #[indexed] donation_treasury_address: &ManagedAddress,
+        #[indexed] setting: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] config_version: u64,
+        change: &ConfigChange<Self::Api>,
     );
 
-    // Emitted whenever max donation percentage is set
-    #[event("setMaxDonationPercentage")]
-    fn max_donation_percentage_event(&self, #[indexed] max_donation_percentage: &u64);
-
-    // Emitted whenever whitelist enabling changes value
-    #[event("whitelistEnableToggle")]
-    fn whitelist_enable_toggle_event(&self, #[indexed] enable_value: &bool);
-
     // Emitted whenever a whitelist spot is set
     #[event("whitelistSpotSet")]
     fn set_whitelist_spot_event(&self, #[indexed] address: &ManagedAddress);
 
+    // Emitted whenever a referrer is registered
+    #[event("referrerAdded")]
+    fn referrer_added_event(&self, #[indexed] referrer: &ManagedAddress);
+
+    // Emitted whenever a referrer is unregistered
+    #[event("referrerRemoved")]
+    fn referrer_removed_event(&self, #[indexed] referrer: &ManagedAddress);
+
+    // Emitted whenever a mint is referred by a registered referrer
+    #[event("referral")]
+    fn referral_event(
+        &self,
+        #[indexed] referrer: &ManagedAddress,
+        #[indexed] minter: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] reward: &BigUint,
+    );
+
+    // Emitted whenever a promo code is registered
+    #[event("promoCodeRegistered")]
+    fn promo_code_registered_event(
+        &self,
+        #[indexed] code_hash: &ManagedByteArray<Self::Api, 32>,
+        promo_code: &PromoCode<Self::Api>,
+    );
+
+    // Emitted whenever a promo code is removed
+    #[event("promoCodeRemoved")]
+    fn promo_code_removed_event(&self, #[indexed] code_hash: &ManagedByteArray<Self::Api, 32>);
+
+    // Emitted whenever a promo code is used for a mint
+    #[event("promoCodeUsed")]
+    fn promo_code_used_event(
+        &self,
+        #[indexed] code_hash: &ManagedByteArray<Self::Api, 32>,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] discount: &BigUint,
+    );
+
+    // Emitted whenever a sponsor deposits funds
+    #[event("sponsorDeposit")]
+    fn sponsor_deposit_event(
+        &self,
+        #[indexed] sponsor: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a sponsor withdraws funds
+    #[event("sponsorWithdraw")]
+    fn sponsor_withdraw_event(
+        &self,
+        #[indexed] sponsor: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a sponsor offers to pay for the mints of a creator
+    #[event("sponsorshipOffered")]
+    fn sponsorship_offered_event(
+        &self,
+        #[indexed] sponsor: &ManagedAddress,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] mint_limit: u64,
+    );
+
+    // Emitted whenever a creator accepts the offer of a sponsor
+    #[event("sponsoredCreatorAdded")]
+    fn sponsored_creator_added_event(
+        &self,
+        #[indexed] sponsor: &ManagedAddress,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] mint_limit: u64,
+    );
+
+    // Emitted whenever a sponsor removes a creator
+    #[event("sponsoredCreatorRemoved")]
+    fn sponsored_creator_removed_event(
+        &self,
+        #[indexed] sponsor: &ManagedAddress,
+        #[indexed] creator: &ManagedAddress,
+    );
+
+    // Emitted whenever the mint of a creator is paid from the balance of its sponsor
+    #[event("sponsoredMint")]
+    fn sponsored_mint_event(
+        &self,
+        #[indexed] sponsor: &ManagedAddress,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever an address deposits mint credits
+    #[event("creditsDeposited")]
+    fn credits_deposited_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever an address withdraws mint credits
+    #[event("creditsWithdrawn")]
+    fn credits_withdrawn_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a mint is paid from the credits of its creator
+    #[event("creditsUsed")]
+    fn credits_used_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a mint fills the global mint limit of the rolling window
+    #[event("globalMintLimitReached")]
+    fn global_mint_limit_reached_event(
+        &self,
+        #[indexed] max_mints: u64,
+        #[indexed] window_sec: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    // Emitted whenever an address is exempted from the global mint limit
+    #[event("rateLimitExemptionAdded")]
+    fn rate_limit_exemption_added_event(&self, #[indexed] address: &ManagedAddress);
+
+    // Emitted whenever an address is no longer exempted from the global mint limit
+    #[event("rateLimitExemptionRemoved")]
+    fn rate_limit_exemption_removed_event(&self, #[indexed] address: &ManagedAddress);
+
+    // Emitted whenever mint passes are deposited to be distributed
+    #[event("mintPassesDeposited")]
+    fn mint_passes_deposited_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever mint passes are sent to an address
+    #[event("mintPassesDistributed")]
+    fn mint_passes_distributed_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a mint pass is consumed by a mint
+    #[event("mintPassUsed")]
+    fn mint_pass_used_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] burned: bool,
+    );
+
     // Emitted whenever a frozen spot is set
     #[event("collectionFreezeListSpotSet")]
     fn set_collection_freeze_list_spot_event(&self, #[indexed] address: &ManagedAddress);
@@ -58,33 +260,13 @@ pub trait EventsModule {
     #[event("whitelistSpotRemoved")]
     fn remove_whitelist_spot_event(&self, #[indexed] address: &ManagedAddress);
 
-    // Emitted whenever the minimum and maximum royalties values changes
-    #[event("setRoyaltiesLimits")]
-    fn set_royalties_limits_event(
-        &self,
-        #[indexed] min_royalties: &BigUint,
-        #[indexed] max_royalties: &BigUint,
-    );
-
-    // Emitted whenever max supply changes
-    #[event("setMaxSupply")]
-    fn set_max_supply_event(&self, #[indexed] max_supply: &BigUint);
-
-    // Emitted whenever a price for the public sale is set
-    #[event("antiSpamTaxSet")]
-    fn set_anti_spam_tax_event(
-        &self,
-        #[indexed] token: &EgldOrEsdtTokenIdentifier,
-        #[indexed] amount: &BigUint,
-    );
-
-    // Emitted whenever the mint time limit changes
-    #[event("mintTimeLimitSet")]
-    fn set_mint_time_limit_event(&self, #[indexed] mint_time_limit: &u64);
+    // Emitted whenever a token is added to the accepted payment tokens
+    #[event("acceptedPaymentTokenAdded")]
+    fn accepted_payment_token_added_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier);
 
-    // Emitted whenever the administrator is set
-    #[event("setAdministrator")]
-    fn set_administrator_event(&self, #[indexed] administrator: &ManagedAddress);
+    // Emitted whenever a token is removed from the accepted payment tokens
+    #[event("acceptedPaymentTokenRemoved")]
+    fn accepted_payment_token_removed_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier);
 
     // Emitted whenever the collection is paused
     #[event("pauseCollection")]
@@ -121,33 +303,97 @@ pub trait EventsModule {
         #[indexed] nonce: u64,
     );
 
-    // Emitted whenever a burn is performed
+    // Emitted whenever a burn is performed, the remaining supply being empty for the nonces whose supply is not tracked
     #[event("burn")]
     fn burn_event(
         &self,
+        #[indexed] version: u32,
         #[indexed] address: &ManagedAddress,
         #[indexed] token_identifier: &TokenIdentifier,
         #[indexed] nonce: u64,
         #[indexed] amount: &BigUint,
+        #[indexed] remaining_supply: &Option<BigUint>,
     );
 
     // Emitted whenever a mint is performed
     #[event("mint")]
     fn mint_event(
         &self,
+        #[indexed] version: u32,
         #[indexed] address: &ManagedAddress,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        data: &MintEventData<Self::Api>,
+    );
+
+    // Emitted whenever a part of the minted supply is sent to the donation treasury
+    #[event("donation")]
+    fn donation_event(
+        &self,
+        #[indexed] donation_treasury_address: &ManagedAddress,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a treasury claims its accumulated anti spam tax
+    #[event("treasuryClaim")]
+    fn treasury_claim_event(
+        &self,
+        #[indexed] treasury: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
         #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever royalties are deposited for a Data NFT-FT nonce
+    #[event("royaltiesDeposited")]
+    fn royalties_deposited_event(
+        &self,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] nonce: u64,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] creator_amount: &BigUint,
+    );
+
+    // Emitted whenever a creator claims their royalties
+    #[event("royaltiesClaimed")]
+    fn royalties_claimed_event(
+        &self,
+        #[indexed] creator: &ManagedAddress,
         #[indexed] token: &EgldOrEsdtTokenIdentifier,
-        #[indexed] price: &BigUint,
-        #[indexed] bond_amount: &BigUint,
-        #[indexed] extra_assets: &ManagedVec<ManagedBuffer>,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a rescue claim is registered
+    #[event("rescueClaimRegistered")]
+    fn rescue_claim_registered_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] id: u64,
+        claim: &RescueClaim<Self::Api>,
     );
 
-    #[event("setWithdrawalAddress")]
-    fn set_withdrawal_address_event(&self, #[indexed] address: &ManagedAddress);
+    // Emitted whenever a rescue claim is cancelled
+    #[event("rescueClaimCancelled")]
+    fn rescue_claim_cancelled_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] id: u64,
+    );
 
-    #[event("setBondContractAddress")]
-    fn set_bond_contract_address_event(&self, #[indexed] address: &ManagedAddress);
+    // Emitted whenever the recipient of a rescue claim receives the tokens
+    #[event("tokensRescued")]
+    fn tokens_rescued_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] id: u64,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] amount: &BigUint,
+    );
 
     #[event("withdrawTokens")]
     fn withdraw_tokens_event(
@@ -156,4 +402,12 @@ pub trait EventsModule {
         #[indexed] token: &EgldOrEsdtTokenIdentifier,
         #[indexed] amount: &BigUint,
     );
+
+    // Emitted whenever several tokens are withdrawn at once
+    #[event("withdrawManyTokens")]
+    fn withdraw_many_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        payments: &ManagedVec<EgldOrEsdtTokenPayment<Self::Api>>,
+    );
 }
@@ -7,22 +7,44 @@ use crate::{
     callbacks::CallbackProxy,
     errors::{
         ERR_ALREADY_IN_WHITELIST, ERR_CONTRACT_ALREADY_INITIALIZED, ERR_DATA_STREAM_IS_EMPTY,
-        ERR_ISSUE_COST, ERR_NOT_IN_WHITELIST, ERR_PERCENTAGE_TOO_HIGH, ERR_WHITELIST_IS_EMPTY,
-        ERR_WRONG_AMOUNT_OF_FUNDS, ERR_WRONG_BOND_PERIOD,
+        ERR_INVALID_GLOBAL_MINT_LIMIT, ERR_INVALID_SURGE_PRICING,
+        ERR_INVALID_TREASURY_DISTRIBUTION, ERR_ISSUE_COST, ERR_NOTHING_TO_CLAIM,
+        ERR_NOT_IN_WHITELIST, ERR_PAYMENT_TOKEN_ALREADY_ACCEPTED, ERR_PERCENTAGE_TOO_HIGH,
+        ERR_PROMO_CODE_ALREADY_REGISTERED, ERR_PROMO_CODE_NOT_FOUND,
+        ERR_REFERRER_ALREADY_REGISTERED, ERR_REFERRER_NOT_REGISTERED, ERR_TOO_MANY_TREASURY_SHARES,
+        ERR_WHITELIST_IS_EMPTY, ERR_WRONG_AMOUNT_OF_FUNDS, ERR_WRONG_BOND_PAYMENT_TOKEN,
+    },
+    events::{MintEventData, BURN_EVENT_VERSION, MINT_EVENT_VERSION},
+    nft_mint_utils::MintOptions,
+    storage::{
+        AuditAction, DataNftAttributes, GlobalMintLimit, PromoCode, PromoDiscount, SurgePricing,
+        TreasuryShare,
     },
-    storage::DataNftAttributes,
 };
 
 pub mod bonding_proxy;
 pub mod callbacks;
 pub mod collection_management;
+pub mod credits;
+pub mod egld_wrapper_proxy;
 pub mod errors;
 pub mod events;
+pub mod mint_pass;
 pub mod nft_mint_utils;
 pub mod requirements;
+pub mod rescue;
+pub mod royalties;
+pub mod sponsorship;
 pub mod storage;
 pub mod views;
 
+// Maximum number of treasuries the anti spam tax can be split between
+pub const MAX_TREASURY_SHARES: usize = 10;
+// Maximum surge multiplier of the anti spam tax in basis points (10x), also bounding each surge step
+pub const MAX_SURGE_MULTIPLIER: u64 = 100_000;
+// Maximum global mints per window, capping the global_mint_timestamps queue iterated by count_global_mints on every mint
+pub const MAX_GLOBAL_MINTS_PER_WINDOW: u64 = 100;
+
 #[multiversx_sc::contract]
 pub trait DataNftMint:
     storage::StorageModule
@@ -32,30 +54,49 @@ pub trait DataNftMint:
     + views::ViewsModule
     + callbacks::Callbacks
     + collection_management::CollectionManagement
+    + royalties::RoyaltiesModule
+    + rescue::RescueModule
+    + sponsorship::SponsorshipModule
+    + credits::CreditsModule
+    + mint_pass::MintPassModule
     + bonding_proxy::BondingContractProxyMethods
+    + egld_wrapper_proxy::EgldWrapperProxyMethods
 {
     // When the smart contract is deployed or upgraded, minting is automatically paused, whitelisting is enabled and default values are set
     #[init]
     fn init(&self) {
+        self.emit_config_changed(b"isPaused", &self.is_paused().get(), &true);
         self.is_paused().set(true);
-        self.mint_pause_toggle_event(&true);
 
+        self.emit_config_changed(b"whitelistEnabled", &self.whitelist_enabled().get(), &true);
         self.whitelist_enabled().set(true);
-        self.whitelist_enable_toggle_event(&true);
 
+        let old_royalties_limits = (self.min_royalties().get(), self.max_royalties().get());
         self.min_royalties().set_if_empty(BigUint::from(0u64));
         self.max_royalties().set_if_empty(BigUint::from(8000u64));
 
-        self.set_royalties_limits_event(&self.min_royalties().get(), &self.max_royalties().get());
+        self.emit_config_changed(
+            b"royaltiesLimits",
+            &old_royalties_limits,
+            &(self.min_royalties().get(), self.max_royalties().get()),
+        );
 
+        let old_max_supply = self.max_supply().get();
         self.max_supply().set_if_empty(&BigUint::from(20u64));
 
-        self.set_max_supply_event(&self.max_supply().get());
+        self.emit_config_changed(b"maxSupply", &old_max_supply, &self.max_supply().get());
     }
 
+    // Contracts deployed before the accepted payment tokens registry only stored the anti spam tax by token, so the token set at initialization is passed again to register it
     #[upgrade]
-    fn upgrade(&self) {
+    fn upgrade(&self, anti_spam_tax_token: OptionalValue<EgldOrEsdtTokenIdentifier>) {
+        self.emit_config_changed(b"isPaused", &self.is_paused().get(), &true);
         self.is_paused().set(true);
+        if let OptionalValue::Some(token) = anti_spam_tax_token {
+            if self.accepted_payment_tokens().insert(token.clone()) {
+                self.accepted_payment_token_added_event(&token);
+            }
+        }
     }
 
     // Endpoint used by the owner in the first place to initialize the contract with all the data needed for the SFT token creation
@@ -72,18 +113,48 @@ pub trait DataNftMint:
         treasury_address: ManagedAddress,
     ) {
         require!(self.token_id().is_empty(), ERR_CONTRACT_ALREADY_INITIALIZED);
+        self.append_audit_entry(
+            AuditAction::InitializeContract,
+            &(
+                collection_name.clone(),
+                token_ticker.clone(),
+                anti_spam_tax_token.clone(),
+                anti_spam_tax_value.clone(),
+                mint_time_limit,
+                treasury_address.clone(),
+            ),
+        );
         let issue_cost = self.call_value().egld_value().clone_value();
         require!(
             issue_cost == BigUint::from(5u64) * BigUint::from(10u64).pow(16u32),
             ERR_ISSUE_COST
         );
 
-        self.set_anti_spam_tax_event(&anti_spam_tax_token, &anti_spam_tax_value);
+        self.emit_config_changed(
+            b"antiSpamTax",
+            &(
+                anti_spam_tax_token.clone(),
+                self.anti_spam_tax(anti_spam_tax_token).get(),
+            ),
+            &(anti_spam_tax_token.clone(), anti_spam_tax_value.clone()),
+        );
         self.anti_spam_tax(anti_spam_tax_token)
             .set(anti_spam_tax_value);
+        self.accepted_payment_tokens()
+            .insert(anti_spam_tax_token.clone());
+        self.accepted_payment_token_added_event(&anti_spam_tax_token);
 
-        self.set_mint_time_limit_event(&mint_time_limit);
+        self.emit_config_changed(
+            b"mintTimeLimit",
+            &self.mint_time_limit().get(),
+            &mint_time_limit,
+        );
         self.mint_time_limit().set(mint_time_limit);
+        self.emit_config_changed(
+            b"treasuryAddress",
+            &self.get_optional_address(self.treasury_address()),
+            &Some(treasury_address.clone()),
+        );
         self.treasury_address().set(&treasury_address);
 
         // Collection issuing
@@ -113,6 +184,7 @@ pub trait DataNftMint:
     #[endpoint(setLocalRoles)]
     fn set_local_roles(&self) {
         self.require_token_issued();
+        self.append_audit_entry(AuditAction::SetLocalRoles, &());
 
         self.send()
             .esdt_system_sc_proxy()
@@ -150,6 +222,79 @@ pub trait DataNftMint:
         lock_period_sec: u64,
         donation_percentage: u64,
         extra_assets: MultiValueEncoded<ManagedBuffer>,
+    ) -> DataNftAttributes<Self::Api> {
+        self.mint_data_nft(
+            name,
+            media,
+            metadata,
+            data_marshal,
+            data_stream,
+            data_preview,
+            royalties,
+            supply,
+            title,
+            description,
+            lock_period_sec,
+            donation_percentage,
+            MintOptions::default(),
+            extra_assets,
+        )
+    }
+
+    // Public endpoint used to mint Data NFT-FTs with the optional mint settings.
+    #[payable("*")]
+    #[endpoint(mintWithOptions)]
+    fn mint_token_with_options(
+        &self,
+        name: ManagedBuffer,
+        media: ManagedBuffer,
+        metadata: ManagedBuffer,
+        data_marshal: ManagedBuffer,
+        data_stream: ManagedBuffer,
+        data_preview: ManagedBuffer,
+        royalties: BigUint,
+        supply: BigUint,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        lock_period_sec: u64,
+        donation_percentage: u64,
+        options: MintOptions<Self::Api>,
+        extra_assets: MultiValueEncoded<ManagedBuffer>,
+    ) -> DataNftAttributes<Self::Api> {
+        self.mint_data_nft(
+            name,
+            media,
+            metadata,
+            data_marshal,
+            data_stream,
+            data_preview,
+            royalties,
+            supply,
+            title,
+            description,
+            lock_period_sec,
+            donation_percentage,
+            options,
+            extra_assets,
+        )
+    }
+
+    fn mint_data_nft(
+        &self,
+        name: ManagedBuffer,
+        media: ManagedBuffer,
+        metadata: ManagedBuffer,
+        data_marshal: ManagedBuffer,
+        data_stream: ManagedBuffer,
+        data_preview: ManagedBuffer,
+        royalties: BigUint,
+        supply: BigUint,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        lock_period_sec: u64,
+        donation_percentage: u64,
+        options: MintOptions<Self::Api>,
+        extra_assets: MultiValueEncoded<ManagedBuffer>,
     ) -> DataNftAttributes<Self::Api> {
         self.require_ready_for_minting_and_burning();
         require!(!data_stream.is_empty(), ERR_DATA_STREAM_IS_EMPTY);
@@ -162,46 +307,111 @@ pub trait DataNftMint:
         self.require_title_description_are_valid(&title, &description);
         self.require_sft_is_valid(&royalties, &supply);
 
-        let donation_supply = if donation_percentage > 0 {
-            require!(
-                donation_percentage <= self.max_donation_percentage().get(),
-                ERR_PERCENTAGE_TOO_HIGH
-            );
-
-            let donation_supply =
-                &supply * &BigUint::from(donation_percentage) / BigUint::from(10_000u64);
-            donation_supply
-        } else {
-            BigUint::zero()
-        };
-
         let caller = self.blockchain().get_caller();
         let current_time = self.blockchain().get_block_timestamp();
-        self.require_minting_is_allowed(&caller, current_time);
+        let (mint_pass, payments) = self.get_mint_pass_and_payments();
+        self.require_minting_is_allowed(&caller, current_time, mint_pass.is_some());
         self.last_mint_time(&caller).set(current_time);
+        self.record_global_mint(&caller, current_time);
 
-        let mut payment = self.call_value().egld_or_single_esdt();
-        let price = self.anti_spam_tax(&payment.token_identifier).get();
-
-        let treasury_address = self.treasury_address().get();
-
-        let bond_amount = self.get_bond_amount_for_lock_period(lock_period_sec);
-
-        require!(bond_amount > BigUint::zero(), ERR_WRONG_BOND_PERIOD);
+        let free_mint = self.get_remaining_free_mints(&caller) > 0;
+        let sponsor = self.get_mint_sponsor(&caller, &payments);
+        let split_payments = self.get_split_mint_payments(&payments);
+        let mut payment = match &split_payments {
+            Some((tax_payment, _)) => tax_payment.clone(),
+            None => self.get_single_mint_payment(&payments),
+        };
+        // a free mint has no anti spam tax to transfer, so the bond is paid alone in the bond payment token
+        if free_mint && payments.len() == 1 && !self.bond_payment_token().is_empty() {
+            require!(
+                payment.token_identifier == self.bond_payment_token().get(),
+                ERR_WRONG_BOND_PAYMENT_TOKEN
+            );
+        }
+        let pays_with_credits = split_payments.is_none()
+            && sponsor.is_none()
+            && payment.amount == 0
+            && !self.credit_tokens(&caller).is_empty();
+        let funds_token = if let Some(sponsor) = &sponsor {
+            self.sponsor_token(sponsor).get()
+        } else if pays_with_credits {
+            let bond_amount = self.get_bond_amount_for_lock_period(lock_period_sec);
+            self.get_credit_payment_token(&caller, &bond_amount, &supply, lock_period_sec)
+        } else {
+            payment.token_identifier.clone()
+        };
+        let pays_wrapped_egld = self.is_wrapped_egld(&funds_token);
+        let tax_token = self.get_anti_spam_tax_token(&funds_token);
+        let mut quote = self.get_mint_quote(
+            &caller,
+            &tax_token,
+            lock_period_sec,
+            &supply,
+            donation_percentage,
+        );
+        self.record_surge_mint(current_time);
+        if !free_mint {
+            if let Some(promo_code) = &options.promo_code {
+                quote.anti_spam_tax =
+                    self.use_promo_code(promo_code, &caller, &tax_token, &quote.anti_spam_tax);
+                quote.total_payment = &quote.anti_spam_tax + &quote.bond_amount;
+            }
+        }
+        if let Some((_, bond_payment)) = &split_payments {
+            require!(
+                bond_payment.amount == quote.bond_amount,
+                ERR_WRONG_AMOUNT_OF_FUNDS
+            );
+            quote.total_payment = quote.anti_spam_tax.clone();
+        }
+        if let Some(sponsor) = &sponsor {
+            payment = self.consume_sponsor_funds(sponsor, &caller, &quote.total_payment);
+        } else if pays_with_credits {
+            self.consume_credits(&caller, &funds_token, &quote.total_payment);
+            payment = EgldOrEsdtTokenPayment::new(funds_token, 0, quote.total_payment.clone());
+        }
+        let refund = match &options.max_payment {
+            Some(max_payment) => {
+                self.refund_overpayment(&caller, &mut payment, &quote.total_payment, max_payment)
+            }
+            None => BigUint::zero(),
+        };
 
         require!(
-            payment.amount == &price + &bond_amount,
+            payment.amount == quote.total_payment,
             ERR_WRONG_AMOUNT_OF_FUNDS
         );
 
+        let price = quote.anti_spam_tax;
         payment.amount -= &price;
 
-        self.send().direct_non_zero(
-            &treasury_address,
-            &payment.token_identifier,
+        // only the anti spam tax is unwrapped, the bond stays in wrapped EGLD
+        let mut tax_payment = EgldOrEsdtTokenPayment::new(
+            payment.token_identifier.clone(),
             payment.token_nonce,
+            price.clone(),
+        );
+        if pays_wrapped_egld && price > 0 {
+            tax_payment = self.unwrap_egld_payment(tax_payment);
+        }
+
+        let referral_reward = self.pay_referral_reward(
+            &options.referrer,
+            &caller,
+            &tax_payment.token_identifier,
+            tax_payment.token_nonce,
             &price,
         );
+        self.distribute_anti_spam_tax(
+            &tax_payment.token_identifier,
+            tax_payment.token_nonce,
+            &(&price - &referral_reward),
+        );
+
+        let bond_payment = match split_payments {
+            Some((_, bond_payment)) => bond_payment,
+            None => payment.clone(),
+        };
 
         let one_token = BigUint::from(1u64);
         self.minted_per_address(&caller)
@@ -221,47 +431,72 @@ pub trait DataNftMint:
 
         let token_identifier = self.token_id().get_token_id();
         let extra_assets_vec = extra_assets.into_vec_of_buffers();
-        self.mint_event(
-            &caller,
-            &one_token,
-            &payment.token_identifier,
-            &price,
-            &payment.amount,
-            &extra_assets_vec,
-        );
+        let data_hash = self.create_hash_buffer(&data_marshal, &data_stream);
 
         let nonce = self.send().esdt_nft_create(
             &token_identifier,
             &supply,
             &name,
             &royalties,
-            &self.create_hash_buffer(&data_marshal, &data_stream),
+            &data_hash,
             &attributes,
-            &self.create_uris(media, metadata, extra_assets_vec),
+            &self.create_uris(media, metadata, extra_assets_vec.clone()),
         );
 
+        self.data_nft_supply(nonce).set(&supply);
+        self.data_nft_creator(nonce).set(&caller);
+
+        self.mint_event(
+            MINT_EVENT_VERSION,
+            &caller,
+            &token_identifier,
+            nonce,
+            &MintEventData {
+                supply: supply.clone(),
+                royalties,
+                lock_period_sec,
+                payment_token: tax_payment.token_identifier,
+                anti_spam_tax: price,
+                bond_token: bond_payment.token_identifier.clone(),
+                bond_amount: bond_payment.amount.clone(),
+                refund,
+                free_mint,
+                donation_supply: quote.donation_supply.clone(),
+                creator_supply: quote.creator_supply.clone(),
+                data_hash,
+                extra_assets: extra_assets_vec,
+            },
+        );
+
+        if let Some(mint_pass) = mint_pass {
+            self.use_mint_pass(&caller, mint_pass);
+        }
+
         self.send_bond(
             &caller,
             token_identifier.clone(),
             nonce,
             lock_period_sec,
-            payment,
+            bond_payment,
         );
 
-        if donation_supply > BigUint::zero() {
+        if quote.donation_supply > BigUint::zero() {
             let donation_treasury_address = self.donation_treasury_address().get();
             self.send().direct_esdt(
                 &donation_treasury_address,
                 &token_identifier,
                 nonce,
-                &donation_supply,
+                &quote.donation_supply,
             );
-            self.send().direct_esdt(
+            self.donation_event(
+                &donation_treasury_address,
                 &caller,
                 &token_identifier,
                 nonce,
-                &(&supply - &donation_supply),
+                &quote.donation_supply,
             );
+            self.send()
+                .direct_esdt(&caller, &token_identifier, nonce, &quote.creator_supply);
         } else {
             self.send()
                 .direct_esdt(&caller, &token_identifier, nonce, &supply);
@@ -282,11 +517,29 @@ pub trait DataNftMint:
         self.require_value_is_positive(&payment.amount);
         self.token_id()
             .nft_burn(payment.token_nonce, &payment.amount);
+
+        // the nonces minted before their supply was tracked have no remaining supply to report
+        let supply_mapper = self.data_nft_supply(payment.token_nonce);
+        let remaining_supply = if supply_mapper.is_empty() {
+            None
+        } else {
+            Some(supply_mapper.update(|supply| {
+                if *supply > payment.amount {
+                    *supply -= &payment.amount;
+                } else {
+                    *supply = BigUint::zero();
+                }
+                supply.clone()
+            }))
+        };
+
         self.burn_event(
+            BURN_EVENT_VERSION,
             &caller,
             &payment.token_identifier,
             payment.token_nonce,
             &payment.amount,
+            &remaining_supply,
         );
     }
 
@@ -294,14 +547,101 @@ pub trait DataNftMint:
     #[only_owner]
     #[endpoint(setTreasuryAddress)]
     fn set_treasury_address(&self, address: ManagedAddress) {
-        self.treasury_address_event(&address);
+        self.append_audit_entry(AuditAction::SetTreasuryAddress, &address);
+        self.emit_config_changed(
+            b"treasuryAddress",
+            &self.get_optional_address(self.treasury_address()),
+            &Some(address.clone()),
+        );
         self.treasury_address().set(&address);
     }
 
+    // Endpoint that will be used by privileged address to split the anti spam tax between treasuries.
+    #[endpoint(setTreasuryDistribution)]
+    fn set_treasury_distribution(
+        &self,
+        shares: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        require!(
+            shares.len() <= MAX_TREASURY_SHARES,
+            ERR_TOO_MANY_TREASURY_SHARES
+        );
+
+        let mut distribution = ManagedVec::new();
+        let mut total_basis_points = 0u64;
+        for share in shares.into_iter() {
+            let (address, basis_points) = share.into_tuple();
+            // bounding every share keeps the total from wrapping around
+            require!(basis_points <= 10_000, ERR_INVALID_TREASURY_DISTRIBUTION);
+            total_basis_points += basis_points;
+            distribution.push(TreasuryShare {
+                address,
+                basis_points,
+            });
+        }
+        require!(
+            distribution.is_empty() || total_basis_points == 10_000,
+            ERR_INVALID_TREASURY_DISTRIBUTION
+        );
+
+        self.append_audit_entry(AuditAction::SetTreasuryDistribution, &distribution);
+        self.emit_config_changed(
+            b"treasuryDistribution",
+            &self.get_treasury_distribution(),
+            &distribution,
+        );
+
+        self.treasury_distribution().clear();
+        for share in distribution.iter() {
+            self.treasury_distribution().push(&share);
+        }
+    }
+
+    // Endpoint that will be used by privileged address to switch between sending the anti spam tax on every mint and accumulating it for the treasuries to claim.
+    #[endpoint(setTreasuryAccumulationEnabled)]
+    fn set_treasury_accumulation_enabled(&self, is_enabled: bool) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.append_audit_entry(AuditAction::SetTreasuryAccumulation, &is_enabled);
+        self.emit_config_changed(
+            b"treasuryAccumulationEnabled",
+            &self.treasury_accumulation_enabled().get(),
+            &is_enabled,
+        );
+        self.treasury_accumulation_enabled().set(is_enabled);
+    }
+
+    // Endpoint used by a treasury to claim the anti spam tax accumulated for it.
+    #[endpoint(claimTreasury)]
+    fn claim_treasury(&self, tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        let caller = self.blockchain().get_caller();
+        let mut claimed_any = false;
+        for token in tokens.into_iter() {
+            let amount = self.treasury_claimable(&caller, &token).take();
+            if amount == BigUint::zero() {
+                continue;
+            }
+            claimed_any = true;
+            self.treasury_claimable_tokens(&caller).swap_remove(&token);
+            self.reserved_funds(&token, 0)
+                .update(|reserved| *reserved -= &amount);
+            self.send().direct(&caller, &token, 0, &amount);
+            self.treasury_claim_event(&caller, &token, &amount);
+        }
+        require!(claimed_any, ERR_NOTHING_TO_CLAIM);
+    }
+
     #[endpoint(setDonationTreasuryAddress)]
     fn set_donation_treasury_address(&self, address: ManagedAddress) {
         self.require_is_privileged(&self.blockchain().get_caller());
-        self.donation_treasury_address_event(&address);
+        self.append_audit_entry(AuditAction::SetDonationTreasuryAddress, &address);
+        self.emit_config_changed(
+            b"donationTreasuryAddress",
+            &self.get_optional_address(self.donation_treasury_address()),
+            &Some(address.clone()),
+        );
         self.donation_treasury_address().set(&address);
     }
 
@@ -309,7 +649,12 @@ pub trait DataNftMint:
     fn set_max_donation_percentage(&self, percentage: u64) {
         self.require_is_privileged(&self.blockchain().get_caller());
         require!(percentage <= 10_000, ERR_PERCENTAGE_TOO_HIGH);
-        self.max_donation_percentage_event(&percentage);
+        self.append_audit_entry(AuditAction::SetMaxDonationPercentage, &percentage);
+        self.emit_config_changed(
+            b"maxDonationPercentage",
+            &self.max_donation_percentage().get(),
+            &percentage,
+        );
         self.max_donation_percentage().set(percentage);
     }
 
@@ -318,7 +663,8 @@ pub trait DataNftMint:
     fn set_is_paused(&self, is_paused: bool) {
         let caller = self.blockchain().get_caller();
         self.require_is_privileged(&caller);
-        self.mint_pause_toggle_event(&is_paused);
+        self.append_audit_entry(AuditAction::SetIsPaused, &is_paused);
+        self.emit_config_changed(b"isPaused", &self.is_paused().get(), &is_paused);
         self.is_paused().set(is_paused);
     }
 
@@ -327,25 +673,233 @@ pub trait DataNftMint:
     fn set_whitelist_enabled(&self, is_enabled: bool) {
         let caller = self.blockchain().get_caller();
         self.require_is_privileged(&caller);
-        self.whitelist_enable_toggle_event(&is_enabled);
+        self.append_audit_entry(AuditAction::SetWhitelistEnabled, &is_enabled);
+        self.emit_config_changed(
+            b"whitelistEnabled",
+            &self.whitelist_enabled().get(),
+            &is_enabled,
+        );
         self.whitelist_enabled().set(is_enabled);
     }
 
-    // Endpoint that will be used by privileged address to set the anti spam tax for a specific token identifier.
+    // Endpoint that will be used by privileged address to set the anti spam tax for an accepted payment token.
     #[endpoint(setAntiSpamTax)]
     fn set_anti_spam_tax(&self, token_id: EgldOrEsdtTokenIdentifier, tax: BigUint) {
         let caller = self.blockchain().get_caller();
         self.require_is_privileged(&caller);
-        self.set_anti_spam_tax_event(&token_id, &tax);
+        self.require_payment_token_is_accepted(&token_id);
+        self.append_audit_entry(
+            AuditAction::SetAntiSpamTax,
+            &(token_id.clone(), tax.clone()),
+        );
+        self.emit_config_changed(
+            b"antiSpamTax",
+            &(token_id.clone(), self.anti_spam_tax(&token_id).get()),
+            &(token_id.clone(), tax.clone()),
+        );
+        self.anti_spam_tax(&token_id).set(tax);
+    }
+
+    // Endpoint that will be used by privileged address to accept a new payment token together with its anti spam tax.
+    #[endpoint(addAcceptedPaymentToken)]
+    fn add_accepted_payment_token(&self, token_id: EgldOrEsdtTokenIdentifier, tax: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.append_audit_entry(
+            AuditAction::AddAcceptedPaymentToken,
+            &(token_id.clone(), tax.clone()),
+        );
+        require!(
+            self.accepted_payment_tokens().insert(token_id.clone()),
+            ERR_PAYMENT_TOKEN_ALREADY_ACCEPTED
+        );
+        self.accepted_payment_token_added_event(&token_id);
+        self.emit_config_changed(
+            b"antiSpamTax",
+            &(token_id.clone(), self.anti_spam_tax(&token_id).get()),
+            &(token_id.clone(), tax.clone()),
+        );
         self.anti_spam_tax(&token_id).set(tax);
     }
 
+    // Endpoint that will be used by privileged address to stop accepting a payment token.
+    #[endpoint(removeAcceptedPaymentToken)]
+    fn remove_accepted_payment_token(&self, token_id: EgldOrEsdtTokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.require_payment_token_is_accepted(&token_id);
+        self.append_audit_entry(AuditAction::RemoveAcceptedPaymentToken, &token_id);
+        self.accepted_payment_tokens().swap_remove(&token_id);
+        self.accepted_payment_token_removed_event(&token_id);
+        self.emit_config_changed(
+            b"antiSpamTax",
+            &(token_id.clone(), self.anti_spam_tax(&token_id).get()),
+            &(token_id.clone(), BigUint::zero()),
+        );
+        self.anti_spam_tax(&token_id).clear();
+        self.anti_spam_tax_per_unit(&token_id).clear();
+    }
+
+    // Endpoint that will be used by privileged address to set the part of the anti spam tax charged for each unit of supply.
+    #[endpoint(setAntiSpamTaxPerUnit)]
+    fn set_anti_spam_tax_per_unit(&self, token_id: EgldOrEsdtTokenIdentifier, tax: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.require_payment_token_is_accepted(&token_id);
+        self.append_audit_entry(
+            AuditAction::SetAntiSpamTaxPerUnit,
+            &(token_id.clone(), tax.clone()),
+        );
+        self.emit_config_changed(
+            b"antiSpamTaxPerUnit",
+            &(
+                token_id.clone(),
+                self.anti_spam_tax_per_unit(&token_id).get(),
+            ),
+            &(token_id.clone(), tax.clone()),
+        );
+        self.anti_spam_tax_per_unit(&token_id).set(tax);
+    }
+
+    // Endpoint that will be used by privileged address to set the anti spam tax multiplier of a lock period in basis points (0 removes it).
+    #[endpoint(setLockPeriodTaxMultiplier)]
+    fn set_lock_period_tax_multiplier(&self, lock_period_sec: u64, basis_points: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.append_audit_entry(
+            AuditAction::SetLockPeriodTaxMultiplier,
+            &(lock_period_sec, basis_points),
+        );
+        let old_multiplier = self
+            .lock_period_tax_multipliers()
+            .get(&lock_period_sec)
+            .unwrap_or_default();
+        self.emit_config_changed(
+            b"lockPeriodTaxMultiplier",
+            &(lock_period_sec, old_multiplier),
+            &(lock_period_sec, basis_points),
+        );
+        if basis_points == 0 {
+            self.lock_period_tax_multipliers().remove(&lock_period_sec);
+        } else {
+            self.lock_period_tax_multipliers()
+                .insert(lock_period_sec, basis_points);
+        }
+    }
+
+    // Endpoint that will be used by privileged address to set the surge pricing of the anti spam tax (a window of 0 disables it).
+    #[endpoint(setSurgePricing)]
+    fn set_surge_pricing(
+        &self,
+        window_sec: u64,
+        threshold: u64,
+        step_basis_points: u64,
+        max_multiplier: u64,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        let surge_pricing = if window_sec == 0 {
+            None
+        } else {
+            require!(
+                max_multiplier >= 10_000
+                    && max_multiplier <= MAX_SURGE_MULTIPLIER
+                    && step_basis_points <= MAX_SURGE_MULTIPLIER,
+                ERR_INVALID_SURGE_PRICING
+            );
+            Some(SurgePricing {
+                window_sec,
+                threshold,
+                step_basis_points,
+                max_multiplier,
+            })
+        };
+        self.append_audit_entry(AuditAction::SetSurgePricing, &surge_pricing);
+
+        let old_surge_pricing = if self.surge_pricing().is_empty() {
+            None
+        } else {
+            Some(self.surge_pricing().get())
+        };
+        self.emit_config_changed(b"surgePricing", &old_surge_pricing, &surge_pricing);
+        match surge_pricing {
+            Some(surge_pricing) => self.surge_pricing().set(surge_pricing),
+            None => self.surge_pricing().clear(),
+        }
+        self.surge_window().clear();
+    }
+
+    // Endpoint that will be used by privileged address to cap the mints of the whole contract over a rolling window (max mints of 0 disables it).
+    #[endpoint(setGlobalMintLimit)]
+    fn set_global_mint_limit(&self, max_mints: u64, window_sec: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        let global_mint_limit = if max_mints == 0 {
+            None
+        } else {
+            require!(
+                window_sec > 0 && max_mints <= MAX_GLOBAL_MINTS_PER_WINDOW,
+                ERR_INVALID_GLOBAL_MINT_LIMIT
+            );
+            Some(GlobalMintLimit {
+                max_mints,
+                window_sec,
+            })
+        };
+        self.append_audit_entry(AuditAction::SetGlobalMintLimit, &global_mint_limit);
+
+        let old_global_mint_limit = if self.global_mint_limit().is_empty() {
+            None
+        } else {
+            Some(self.global_mint_limit().get())
+        };
+        self.emit_config_changed(
+            b"globalMintLimit",
+            &old_global_mint_limit,
+            &global_mint_limit,
+        );
+        match global_mint_limit {
+            Some(global_mint_limit) => self.global_mint_limit().set(global_mint_limit),
+            None => {
+                self.global_mint_limit().clear();
+                self.global_mint_timestamps().clear();
+            }
+        }
+    }
+
+    // Endpoint that will be used by privileged address to exempt addresses from the global mint limit.
+    #[endpoint(addRateLimitExemptions)]
+    fn add_rate_limit_exemptions(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.append_audit_entry(AuditAction::AddRateLimitExemptions, &addresses.to_vec());
+        for address in addresses.into_iter() {
+            if self.rate_limit_exempt_addresses().insert(address.clone()) {
+                self.rate_limit_exemption_added_event(&address);
+            }
+        }
+    }
+
+    // Endpoint that will be used by privileged address to subject addresses to the global mint limit again.
+    #[endpoint(removeRateLimitExemptions)]
+    fn remove_rate_limit_exemptions(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.append_audit_entry(AuditAction::RemoveRateLimitExemptions, &addresses.to_vec());
+        for address in addresses.into_iter() {
+            if self.rate_limit_exempt_addresses().swap_remove(&address) {
+                self.rate_limit_exemption_removed_event(&address);
+            }
+        }
+    }
+
     // Endpoint that will be used by the owner and privileged address to set whitelist spots.
     #[endpoint(setWhiteListSpots)]
     fn set_whitelist_spots(&self, whitelist: MultiValueEncoded<ManagedAddress>) {
         require!(!whitelist.is_empty(), ERR_WHITELIST_IS_EMPTY);
         let caller = self.blockchain().get_caller();
         self.require_is_privileged(&caller);
+        self.append_audit_entry(AuditAction::SetWhitelistSpots, &whitelist.to_vec());
         for item in whitelist.into_iter() {
             if self.whitelist().insert(item.clone()) {
                 self.set_whitelist_spot_event(&item);
@@ -361,6 +915,7 @@ pub trait DataNftMint:
         require!(!whitelist.is_empty(), ERR_WHITELIST_IS_EMPTY);
         let caller = self.blockchain().get_caller();
         self.require_is_privileged(&caller);
+        self.append_audit_entry(AuditAction::RemoveWhitelistSpots, &whitelist.to_vec());
         for item in whitelist.into_iter() {
             if self.whitelist().remove(&item.clone()) {
                 self.remove_whitelist_spot_event(&item);
@@ -370,21 +925,142 @@ pub trait DataNftMint:
         }
     }
 
+    // Endpoint that will be used by the owner and privileged address to register referrers.
+    #[endpoint(addReferrers)]
+    fn add_referrers(&self, referrers: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.append_audit_entry(AuditAction::AddReferrers, &referrers.to_vec());
+        for referrer in referrers.into_iter() {
+            require!(
+                self.referrers().insert(referrer.clone()),
+                ERR_REFERRER_ALREADY_REGISTERED
+            );
+            self.referrer_added_event(&referrer);
+        }
+    }
+
+    // Endpoint that will be used by the owner and privileged address to unregister referrers.
+    #[endpoint(removeReferrers)]
+    fn remove_referrers(&self, referrers: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.append_audit_entry(AuditAction::RemoveReferrers, &referrers.to_vec());
+        for referrer in referrers.into_iter() {
+            require!(
+                self.referrers().swap_remove(&referrer),
+                ERR_REFERRER_NOT_REGISTERED
+            );
+            self.referrer_removed_event(&referrer);
+        }
+    }
+
+    // Endpoint that will be used by the owner and privileged address to set the share of the anti spam tax paid to referrers.
+    #[endpoint(setReferralShare)]
+    fn set_referral_share(&self, basis_points: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        require!(basis_points <= 10_000, ERR_PERCENTAGE_TOO_HIGH);
+        self.append_audit_entry(AuditAction::SetReferralShare, &basis_points);
+        self.emit_config_changed(
+            b"referralShare",
+            &self.referral_share().get(),
+            &basis_points,
+        );
+        self.referral_share().set(basis_points);
+    }
+
+    // Endpoint that will be used by the owner and privileged address to register a promo code by the sha256 hash of the code.
+    #[endpoint(registerPromoCode)]
+    fn register_promo_code(
+        &self,
+        code_hash: ManagedByteArray<Self::Api, 32>,
+        discount: PromoDiscount<Self::Api>,
+        max_uses: u64,
+        expires_at: u64,
+        max_uses_per_address: u64,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        require!(
+            self.promo_code(&code_hash).is_empty(),
+            ERR_PROMO_CODE_ALREADY_REGISTERED
+        );
+        if let PromoDiscount::BasisPoints(basis_points) = &discount {
+            require!(*basis_points <= 10_000, ERR_PERCENTAGE_TOO_HIGH);
+        }
+
+        let promo_code = PromoCode {
+            discount,
+            max_uses,
+            uses: 0,
+            expires_at,
+            max_uses_per_address,
+        };
+        self.append_audit_entry(
+            AuditAction::RegisterPromoCode,
+            &(code_hash.clone(), promo_code.clone()),
+        );
+        self.promo_code(&code_hash).set(&promo_code);
+        self.promo_code_registered_event(&code_hash, &promo_code);
+    }
+
+    // Endpoint that will be used by the owner and privileged address to remove a promo code.
+    #[endpoint(removePromoCode)]
+    fn remove_promo_code(&self, code_hash: ManagedByteArray<Self::Api, 32>) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        require!(
+            !self.promo_code(&code_hash).is_empty(),
+            ERR_PROMO_CODE_NOT_FOUND
+        );
+        self.append_audit_entry(AuditAction::RemovePromoCode, &code_hash);
+        self.promo_code(&code_hash).clear();
+        self.promo_code_removed_event(&code_hash);
+    }
+
     // Endpoint that will be used by the owner to set mint time limit.
     #[only_owner]
     #[endpoint(setMintTimeLimit)]
     fn set_mint_time_limit(&self, mint_time_limit: u64) {
-        self.set_mint_time_limit_event(&mint_time_limit);
+        self.append_audit_entry(AuditAction::SetMintTimeLimit, &mint_time_limit);
+        self.emit_config_changed(
+            b"mintTimeLimit",
+            &self.mint_time_limit().get(),
+            &mint_time_limit,
+        );
         self.mint_time_limit().set(mint_time_limit);
     }
 
+    // Endpoint that will be used by privileged address to set the number of first mints of every address that carry no anti spam tax.
+    #[endpoint(setFreeMintsPerAddress)]
+    fn set_free_mints_per_address(&self, free_mints: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.append_audit_entry(AuditAction::SetFreeMintsPerAddress, &free_mints);
+        self.emit_config_changed(
+            b"freeMintsPerAddress",
+            &self.free_mints_per_address().get(),
+            &free_mints,
+        );
+        self.free_mints_per_address().set(free_mints);
+    }
+
     // Endpoint that will be used by the owner and privileged address to set min and max royalties.
     #[endpoint(setRoyaltiesLimits)]
     fn set_royalties_limits(&self, min_royalties: BigUint, max_royalties: BigUint) {
         let caller = self.blockchain().get_caller();
         self.require_is_privileged(&caller);
         self.require_royalties_are_valid(&min_royalties, &max_royalties);
-        self.set_royalties_limits_event(&min_royalties, &max_royalties);
+        self.append_audit_entry(
+            AuditAction::SetRoyaltiesLimits,
+            &(min_royalties.clone(), max_royalties.clone()),
+        );
+        self.emit_config_changed(
+            b"royaltiesLimits",
+            &(self.min_royalties().get(), self.max_royalties().get()),
+            &(min_royalties.clone(), max_royalties.clone()),
+        );
         self.min_royalties().set(min_royalties);
         self.max_royalties().set(max_royalties);
     }
@@ -394,7 +1070,8 @@ pub trait DataNftMint:
     fn set_max_supply(&self, max_supply: BigUint) {
         let caller = self.blockchain().get_caller();
         self.require_is_privileged(&caller);
-        self.set_max_supply_event(&max_supply);
+        self.append_audit_entry(AuditAction::SetMaxSupply, &max_supply);
+        self.emit_config_changed(b"maxSupply", &self.max_supply().get(), &max_supply);
         self.max_supply().set(max_supply);
     }
 
@@ -402,7 +1079,12 @@ pub trait DataNftMint:
     #[only_owner]
     #[endpoint(setAdministrator)]
     fn set_administrator(&self, administrator: ManagedAddress) {
-        self.set_administrator_event(&administrator);
+        self.append_audit_entry(AuditAction::SetAdministrator, &administrator);
+        self.emit_config_changed(
+            b"administrator",
+            &self.get_optional_address(self.administrator()),
+            &Some(administrator.clone()),
+        );
         self.administrator().set(&administrator);
     }
 
@@ -410,18 +1092,88 @@ pub trait DataNftMint:
     #[only_owner]
     #[endpoint(setBondContractAddress)]
     fn set_bond_contract_address(&self, bond_contract_address: ManagedAddress) {
-        self.set_bond_contract_address_event(&bond_contract_address);
+        self.append_audit_entry(AuditAction::SetBondContractAddress, &bond_contract_address);
+        self.emit_config_changed(
+            b"bondContractAddress",
+            &self.get_optional_address(self.bond_contract_address()),
+            &Some(bond_contract_address.clone()),
+        );
         self.bond_contract_address().set(&bond_contract_address);
     }
 
+    // Endpoint to set the token of the bond transfer when the anti spam tax is paid in a separate transfer
+    #[only_owner]
+    #[endpoint(setBondPaymentToken)]
+    fn set_bond_payment_token(&self, token: TokenIdentifier) {
+        self.append_audit_entry(AuditAction::SetBondPaymentToken, &token);
+        let old_token = if self.bond_payment_token().is_empty() {
+            None
+        } else {
+            Some(self.bond_payment_token().get())
+        };
+        self.emit_config_changed(b"bondPaymentToken", &old_token, &Some(token.clone()));
+        self.bond_payment_token().set(&token);
+    }
+
+    // Endpoint to set the wrapped EGLD token and the egld-esdt-swap contract unwrapping it when used as payment
+    #[only_owner]
+    #[endpoint(setEgldWrapper)]
+    fn set_egld_wrapper(
+        &self,
+        wrapped_egld_token_id: TokenIdentifier,
+        egld_wrapper_address: ManagedAddress,
+    ) {
+        self.append_audit_entry(
+            AuditAction::SetEgldWrapper,
+            &(wrapped_egld_token_id.clone(), egld_wrapper_address.clone()),
+        );
+        let old_token = if self.wrapped_egld_token_id().is_empty() {
+            None
+        } else {
+            Some(self.wrapped_egld_token_id().get())
+        };
+        self.emit_config_changed(
+            b"egldWrapper",
+            &(
+                old_token,
+                self.get_optional_address(self.egld_wrapper_address()),
+            ),
+            &(
+                Some(wrapped_egld_token_id.clone()),
+                Some(egld_wrapper_address.clone()),
+            ),
+        );
+        self.wrapped_egld_token_id().set(&wrapped_egld_token_id);
+        self.egld_wrapper_address().set(&egld_wrapper_address);
+    }
+
     // Endpoint to set the withdraw address to collect 3rd party royalties into
     #[only_owner]
     #[endpoint(setWithdrawalAddress)]
     fn set_withdrawal_address(&self, withdrawal_address: ManagedAddress) {
-        self.set_withdrawal_address_event(&withdrawal_address);
+        self.append_audit_entry(AuditAction::SetWithdrawalAddress, &withdrawal_address);
+        self.emit_config_changed(
+            b"withdrawalAddress",
+            &self.get_optional_address(self.withdrawal_address()),
+            &Some(withdrawal_address.clone()),
+        );
         self.withdrawal_address().set(&withdrawal_address);
     }
 
+    // Endpoint that will be used by the owner to set how many audit log entries are kept (0 keeps all of them)
+    #[only_owner]
+    #[endpoint(setAuditLogRetention)]
+    fn set_audit_log_retention(&self, retention: u64) {
+        self.append_audit_entry(AuditAction::SetAuditLogRetention, &retention);
+        self.emit_config_changed(
+            b"auditLogRetention",
+            &self.audit_log_retention().get(),
+            &retention,
+        );
+        self.audit_log_retention().set(retention);
+        self.prune_audit_log();
+    }
+
     // Endpoint for approved withdrawer to withdraw 3rd party royalties
     #[endpoint(withdraw)]
     fn withdraw(&self, token_identifier: EgldOrEsdtTokenIdentifier, nonce: u64, amount: BigUint) {
@@ -430,8 +1182,12 @@ pub trait DataNftMint:
         self.require_withdrawal_address_is_set();
         let withdrawal_address = self.withdrawal_address().get();
         self.require_is_withdrawal_address(&caller);
+        self.append_audit_entry(
+            AuditAction::Withdraw,
+            &(token_identifier.clone(), nonce, amount.clone()),
+        );
 
-        let balance = self.blockchain().get_sc_balance(&token_identifier, nonce);
+        let balance = self.get_available_balance(&token_identifier, nonce);
 
         self.require_value_is_positive(&amount);
         if balance > BigUint::zero() && amount <= balance {
@@ -443,4 +1199,66 @@ pub trait DataNftMint:
             sc_panic!(ERR_WRONG_AMOUNT_OF_FUNDS);
         }
     }
+
+    // Endpoint for approved withdrawer to withdraw several tokens at once, an amount of 0 withdrawing the whole available balance
+    #[endpoint(withdrawMany)]
+    fn withdraw_many(
+        &self,
+        withdrawals: MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, u64, BigUint>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+
+        self.require_withdrawal_address_is_set();
+        let withdrawal_address = self.withdrawal_address().get();
+        self.require_is_withdrawal_address(&caller);
+
+        let mut payments: ManagedVec<EgldOrEsdtTokenPayment> = ManagedVec::new();
+        for withdrawal in withdrawals.into_iter() {
+            let (token_identifier, nonce, amount) = withdrawal.into_tuple();
+            let mut available = self.get_available_balance(&token_identifier, nonce);
+            for payment in payments.iter() {
+                if payment.token_identifier == token_identifier && payment.token_nonce == nonce {
+                    available -= &payment.amount;
+                }
+            }
+
+            let amount = if amount == BigUint::zero() {
+                if available == BigUint::zero() {
+                    continue;
+                }
+                available.clone()
+            } else {
+                amount
+            };
+            require!(amount <= available, ERR_WRONG_AMOUNT_OF_FUNDS);
+            payments.push(EgldOrEsdtTokenPayment::new(token_identifier, nonce, amount));
+        }
+        require!(!payments.is_empty(), ERR_WRONG_AMOUNT_OF_FUNDS);
+
+        self.append_audit_entry(AuditAction::WithdrawMany, &payments);
+
+        let mut egld_amount = BigUint::zero();
+        let mut esdt_payments = ManagedVec::new();
+        for payment in payments.iter() {
+            if payment.token_identifier.is_egld() {
+                egld_amount += &payment.amount;
+            } else {
+                esdt_payments.push(EsdtTokenPayment::new(
+                    payment.token_identifier.unwrap_esdt(),
+                    payment.token_nonce,
+                    payment.amount,
+                ));
+            }
+        }
+
+        if egld_amount > BigUint::zero() {
+            self.send().direct_egld(&withdrawal_address, &egld_amount);
+        }
+        if !esdt_payments.is_empty() {
+            self.send()
+                .direct_multi(&withdrawal_address, &esdt_payments);
+        }
+
+        self.withdraw_many_event(&caller, &payments);
+    }
 }
@@ -0,0 +1,216 @@
+use crate::{
+    errors::{
+        ERR_INVALID_MINT_PASS, ERR_INVALID_MINT_PASS_TOKEN, ERR_MINT_PASSES_STILL_RESERVED,
+        ERR_MINT_PASS_TOKEN_NOT_SET, ERR_MISSING_MINT_PASS_BURN_ROLE, ERR_NOT_ENOUGH_MINT_PASSES,
+        ERR_WRONG_AMOUNT_OF_PAYMENT,
+    },
+    storage::AuditAction,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(Clone, PartialEq, Debug, TopDecode, TopEncode, NestedDecode, NestedEncode, TypeAbi)]
+pub struct MintPassConfigOut<M: ManagedTypeApi> {
+    pub token: Option<TokenIdentifier<M>>,
+    pub burn_on_use: bool,
+    pub available: BigUint<M>,
+    pub used: u64,
+}
+
+// Module that handles mint passes, fungible tokens sent along a mint and consumed as proof of eligibility instead of a whitelist spot
+#[multiversx_sc::module]
+pub trait MintPassModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::requirements::RequirementsModule
+{
+    // Endpoint that will be used by privileged address to register the mint pass token and whether used passes are burned or returned to the treasury.
+    #[endpoint(setMintPassToken)]
+    fn set_mint_pass_token(&self, token: TokenIdentifier, burn_on_use: bool) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        require!(
+            token.is_valid_esdt_identifier(),
+            ERR_INVALID_MINT_PASS_TOKEN
+        );
+        if burn_on_use {
+            require!(
+                self.blockchain()
+                    .get_esdt_local_roles(&token)
+                    .has_role(&EsdtLocalRole::Burn),
+                ERR_MISSING_MINT_PASS_BURN_ROLE
+            );
+        }
+        let old_token = if self.mint_pass_token().is_empty() {
+            None
+        } else {
+            Some(self.mint_pass_token().get())
+        };
+        // the passes deposited in the current token have to be distributed before switching token
+        if let Some(old_token) = &old_token {
+            require!(
+                old_token == &token
+                    || self
+                        .reserved_funds(&EgldOrEsdtTokenIdentifier::esdt(old_token.clone()), 0)
+                        .get()
+                        == 0,
+                ERR_MINT_PASSES_STILL_RESERVED
+            );
+        }
+        self.append_audit_entry(AuditAction::SetMintPassToken, &(token.clone(), burn_on_use));
+
+        self.emit_config_changed(
+            b"mintPassToken",
+            &(old_token, self.mint_pass_burn_on_use().get()),
+            &(Some(token.clone()), burn_on_use),
+        );
+        self.mint_pass_token().set(&token);
+        self.mint_pass_burn_on_use().set(burn_on_use);
+    }
+
+    // Endpoint that will be used by privileged address to deposit the mint passes to distribute.
+    #[payable("*")]
+    #[endpoint(depositMintPasses)]
+    fn deposit_mint_passes(&self) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        require!(
+            !self.mint_pass_token().is_empty(),
+            ERR_MINT_PASS_TOKEN_NOT_SET
+        );
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.mint_pass_token().get(),
+            ERR_INVALID_MINT_PASS
+        );
+        self.append_audit_entry(
+            AuditAction::DepositMintPasses,
+            &(payment.token_identifier.clone(), payment.amount.clone()),
+        );
+
+        // deposited passes are kept for distribution, out of reach of the withdrawal address
+        let reserved_token = EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier.clone());
+        self.reserved_funds(&reserved_token, 0)
+            .update(|reserved| *reserved += &payment.amount);
+        self.mint_passes_deposited_event(&caller, &payment.token_identifier, &payment.amount);
+    }
+
+    // Endpoint that will be used by privileged address to send deposited mint passes to addresses.
+    #[endpoint(distributeMintPasses)]
+    fn distribute_mint_passes(
+        &self,
+        recipients: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        require!(
+            !self.mint_pass_token().is_empty(),
+            ERR_MINT_PASS_TOKEN_NOT_SET
+        );
+
+        let token = self.mint_pass_token().get();
+        let reserved_token = EgldOrEsdtTokenIdentifier::esdt(token.clone());
+        let mut addresses = ManagedVec::<Self::Api, ManagedAddress>::new();
+        let mut amounts = ManagedVec::<Self::Api, BigUint>::new();
+        for recipient in recipients.into_iter() {
+            let (address, amount) = recipient.into_tuple();
+            self.require_value_is_positive(&amount);
+            let reserved_mapper = self.reserved_funds(&reserved_token, 0);
+            require!(amount <= reserved_mapper.get(), ERR_NOT_ENOUGH_MINT_PASSES);
+            reserved_mapper.update(|reserved| *reserved -= &amount);
+            self.send().direct_esdt(&address, &token, 0, &amount);
+            self.mint_passes_distributed_event(&address, &token, &amount);
+            addresses.push(address);
+            amounts.push(amount);
+        }
+        self.append_audit_entry(AuditAction::DistributeMintPasses, &(addresses, amounts));
+    }
+
+    // Returns the mint pass sent along a mint, if any, and the transfers paying for the mint
+    fn get_mint_pass_and_payments(
+        &self,
+    ) -> (Option<EsdtTokenPayment>, ManagedVec<EgldOrEsdtTokenPayment>) {
+        let mut payments = ManagedVec::new();
+        let egld_value = self.call_value().egld_value().clone_value();
+        if egld_value > 0 {
+            payments.push(EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                egld_value,
+            ));
+            return (None, payments);
+        }
+
+        let mut mint_pass = None;
+        for transfer in self.call_value().all_esdt_transfers().iter() {
+            if !self.mint_pass_token().is_empty()
+                && transfer.token_identifier == self.mint_pass_token().get()
+            {
+                require!(
+                    mint_pass.is_none() && transfer.amount == 1u64,
+                    ERR_INVALID_MINT_PASS
+                );
+                mint_pass = Some(transfer);
+            } else {
+                payments.push(transfer.into());
+            }
+        }
+        (mint_pass, payments)
+    }
+
+    // Returns the single payment of a mint, nothing sent meaning no payment
+    fn get_single_mint_payment(
+        &self,
+        payments: &ManagedVec<EgldOrEsdtTokenPayment>,
+    ) -> EgldOrEsdtTokenPayment {
+        match payments.len() {
+            0 => EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, BigUint::zero()),
+            1 => payments.get(0),
+            _ => sc_panic!(ERR_WRONG_AMOUNT_OF_PAYMENT),
+        }
+    }
+
+    // Burns the mint pass of a successful mint or returns it to the treasury
+    fn use_mint_pass(&self, caller: &ManagedAddress, mint_pass: EsdtTokenPayment) {
+        let burn_on_use = self.mint_pass_burn_on_use().get();
+        if burn_on_use {
+            self.send().esdt_local_burn(
+                &mint_pass.token_identifier,
+                mint_pass.token_nonce,
+                &mint_pass.amount,
+            );
+        } else {
+            self.send().direct_esdt(
+                &self.treasury_address().get(),
+                &mint_pass.token_identifier,
+                mint_pass.token_nonce,
+                &mint_pass.amount,
+            );
+        }
+        self.mint_passes_used().update(|used| *used += 1);
+        self.mint_pass_used_event(caller, &mint_pass.token_identifier, burn_on_use);
+    }
+
+    // View that returns the mint pass token, what happens to used passes and how many are left to distribute and used
+    #[view(getMintPassConfig)]
+    fn get_mint_pass_config(&self) -> MintPassConfigOut<Self::Api> {
+        let token_mapper = self.mint_pass_token();
+        let (token, available) = if token_mapper.is_empty() {
+            (None, BigUint::zero())
+        } else {
+            let token = token_mapper.get();
+            let available = self
+                .reserved_funds(&EgldOrEsdtTokenIdentifier::esdt(token.clone()), 0)
+                .get();
+            (Some(token), available)
+        };
+
+        MintPassConfigOut {
+            token,
+            burn_on_use: self.mint_pass_burn_on_use().get(),
+            available,
+            used: self.mint_passes_used().get(),
+        }
+    }
+}
@@ -1,8 +1,38 @@
+use crate::{
+    errors::{
+        ERR_BOND_PAYMENT_TOKEN_NOT_SET, ERR_MINT_PRICE_ABOVE_MAX_PAYMENT,
+        ERR_WRONG_AMOUNT_OF_FUNDS, ERR_WRONG_BOND_PAYMENT_TOKEN,
+    },
+    storage::{PromoCode, PromoDiscount, SurgeWindow},
+};
+
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+// Optional settings of a mint
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct MintOptions<M: ManagedTypeApi> {
+    pub referrer: Option<ManagedAddress<M>>,
+    pub promo_code: Option<ManagedBuffer<M>>,
+    pub max_payment: Option<BigUint<M>>,
+}
+
+impl<M: ManagedTypeApi> Default for MintOptions<M> {
+    fn default() -> Self {
+        MintOptions {
+            referrer: None,
+            promo_code: None,
+            max_payment: None,
+        }
+    }
+}
+
 #[multiversx_sc::module]
-pub trait NftMintUtils: crate::storage::StorageModule {
+pub trait NftMintUtils:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::requirements::RequirementsModule
+{
     fn create_hash_buffer(
         &self,
         data_marshal: &ManagedBuffer,
@@ -26,4 +56,297 @@ pub trait NftMintUtils: crate::storage::StorageModule {
         uris.append_vec(extra_assets);
         uris
     }
+
+    // Computes how many units of the supply are sent to the donation treasury
+    fn get_donation_supply(&self, supply: &BigUint, donation_percentage: u64) -> BigUint {
+        if donation_percentage == 0 {
+            return BigUint::zero();
+        }
+
+        self.require_check(self.check_donation_percentage_is_valid(donation_percentage));
+
+        supply * &BigUint::from(donation_percentage) / BigUint::from(10_000u64)
+    }
+
+    fn get_treasury_share_amount(&self, tax: &BigUint, basis_points: u64) -> BigUint {
+        tax * &BigUint::from(basis_points) / BigUint::from(10_000u64)
+    }
+
+    // Computes the anti spam tax of a mint from the base fee, the per unit fee, the multiplier of the lock period and the surge multiplier
+    fn compute_anti_spam_tax(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        supply: &BigUint,
+        lock_period_sec: u64,
+    ) -> BigUint {
+        let tax =
+            self.anti_spam_tax(token).get() + &self.anti_spam_tax_per_unit(token).get() * supply;
+        let tax = match self.lock_period_tax_multipliers().get(&lock_period_sec) {
+            Some(multiplier) => tax * &BigUint::from(multiplier) / BigUint::from(10_000u64),
+            None => tax,
+        };
+        let surge_multiplier = self.get_surge_multiplier(self.blockchain().get_block_timestamp());
+        tax * &BigUint::from(surge_multiplier) / BigUint::from(10_000u64)
+    }
+
+    // Returns the mints of the fixed window containing the current time and of the one before it
+    fn get_surge_window(&self, window_sec: u64, current_time: u64) -> SurgeWindow {
+        let start = current_time - current_time % window_sec;
+        let window = self.surge_window().get();
+        if window.start == start {
+            window
+        } else if window.start + window_sec == start {
+            SurgeWindow {
+                start,
+                mints: 0,
+                previous_mints: window.mints,
+            }
+        } else {
+            SurgeWindow {
+                start,
+                mints: 0,
+                previous_mints: 0,
+            }
+        }
+    }
+
+    // Returns the surge multiplier of the anti spam tax in basis points, 10000 when surge pricing is disabled or the window is quiet
+    fn get_surge_multiplier(&self, current_time: u64) -> u64 {
+        if self.surge_pricing().is_empty() {
+            return 10_000;
+        }
+        let surge_pricing = self.surge_pricing().get();
+        let window = self.get_surge_window(surge_pricing.window_sec, current_time);
+
+        // the previous window is weighted by how much of it still overlaps the sliding window
+        let elapsed = current_time - window.start;
+        let recent_mints = window.mints.saturating_add(
+            window
+                .previous_mints
+                .saturating_mul(surge_pricing.window_sec - elapsed)
+                / surge_pricing.window_sec,
+        );
+        if recent_mints <= surge_pricing.threshold {
+            return 10_000;
+        }
+        let multiplier = (recent_mints - surge_pricing.threshold)
+            .saturating_mul(surge_pricing.step_basis_points)
+            .saturating_add(10_000);
+        core::cmp::min(multiplier, surge_pricing.max_multiplier)
+    }
+
+    // Counts a mint in the surge pricing window
+    fn record_surge_mint(&self, current_time: u64) {
+        if self.surge_pricing().is_empty() {
+            return;
+        }
+        let mut window = self.get_surge_window(self.surge_pricing().get().window_sec, current_time);
+        window.mints += 1;
+        self.surge_window().set(window);
+    }
+
+    // Counts a mint for the global mint limit, dropping the timestamps that left the rolling window
+    fn record_global_mint(&self, address: &ManagedAddress, current_time: u64) {
+        if self.global_mint_limit().is_empty()
+            || self.rate_limit_exempt_addresses().contains(address)
+        {
+            return;
+        }
+        let global_mint_limit = self.global_mint_limit().get();
+        let mut timestamps = self.global_mint_timestamps();
+        while let Some(timestamp) = timestamps.front() {
+            if timestamp + global_mint_limit.window_sec > current_time {
+                break;
+            }
+            timestamps.pop_front();
+        }
+        timestamps.push_back(current_time);
+        if timestamps.len() as u64 >= global_mint_limit.max_mints {
+            self.global_mint_limit_reached_event(
+                global_mint_limit.max_mints,
+                global_mint_limit.window_sec,
+                current_time,
+            );
+        }
+    }
+
+    // Returns how many of the free mints of the address are left
+    fn get_remaining_free_mints(&self, address: &ManagedAddress) -> u64 {
+        let minted = self
+            .minted_per_address(address)
+            .get()
+            .to_u64()
+            .unwrap_or(u64::MAX);
+        self.free_mints_per_address().get().saturating_sub(minted)
+    }
+
+    // Applies the discount of a promo code to the anti spam tax
+    fn get_discounted_tax(&self, promo_code: &PromoCode<Self::Api>, tax: &BigUint) -> BigUint {
+        let discount = match &promo_code.discount {
+            PromoDiscount::BasisPoints(basis_points) => {
+                tax * &BigUint::from(*basis_points) / BigUint::from(10_000u64)
+            }
+            PromoDiscount::FixedAmount(_, amount) => amount.clone(),
+        };
+        if &discount >= tax {
+            BigUint::zero()
+        } else {
+            tax - &discount
+        }
+    }
+
+    // Uses a promo code for a mint of the caller, returning the discounted anti spam tax
+    fn use_promo_code(
+        &self,
+        code: &ManagedBuffer,
+        caller: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        tax: &BigUint,
+    ) -> BigUint {
+        let code_hash = self.crypto().sha256(code);
+        self.require_check(self.check_promo_code_is_usable(
+            &code_hash,
+            Some(caller),
+            token,
+            self.blockchain().get_block_timestamp(),
+        ));
+
+        let promo_code = self.promo_code(&code_hash).update(|promo_code| {
+            promo_code.uses += 1;
+            promo_code.clone()
+        });
+        self.promo_code_uses(&code_hash, caller)
+            .update(|uses| *uses += 1);
+
+        let discounted_tax = self.get_discounted_tax(&promo_code, tax);
+        self.promo_code_used_event(&code_hash, caller, token, &(tax - &discounted_tax));
+        discounted_tax
+    }
+
+    // Returns the anti spam tax and bond transfers of a mint paid with two separate ESDT transfers
+    fn get_split_mint_payments(
+        &self,
+        payments: &ManagedVec<EgldOrEsdtTokenPayment>,
+    ) -> Option<(EgldOrEsdtTokenPayment, EgldOrEsdtTokenPayment)> {
+        if payments.len() != 2 {
+            return None;
+        }
+
+        require!(
+            !self.bond_payment_token().is_empty(),
+            ERR_BOND_PAYMENT_TOKEN_NOT_SET
+        );
+        let tax_payment = payments.get(0);
+        let bond_payment = payments.get(1);
+        require!(
+            bond_payment.token_identifier == self.bond_payment_token().get(),
+            ERR_WRONG_BOND_PAYMENT_TOKEN
+        );
+        Some((tax_payment, bond_payment))
+    }
+
+    // Accepts a payment up to the maximum set by the caller, refunding what exceeds the mint price
+    fn refund_overpayment(
+        &self,
+        caller: &ManagedAddress,
+        payment: &mut EgldOrEsdtTokenPayment,
+        total_payment: &BigUint,
+        max_payment: &BigUint,
+    ) -> BigUint {
+        require!(
+            total_payment <= max_payment,
+            ERR_MINT_PRICE_ABOVE_MAX_PAYMENT
+        );
+        require!(
+            &payment.amount >= total_payment && &payment.amount <= max_payment,
+            ERR_WRONG_AMOUNT_OF_FUNDS
+        );
+
+        let refund = &payment.amount - total_payment;
+        payment.amount = total_payment.clone();
+        self.send().direct_non_zero(
+            caller,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &refund,
+        );
+        refund
+    }
+
+    // Pays a registered referrer (other than the caller) its share of the anti spam tax, returning the amount paid
+    fn pay_referral_reward(
+        &self,
+        referrer: &Option<ManagedAddress>,
+        caller: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        nonce: u64,
+        tax: &BigUint,
+    ) -> BigUint {
+        let referrer = match referrer {
+            Some(referrer) if referrer != caller && self.referrers().contains(referrer) => referrer,
+            _ => return BigUint::zero(),
+        };
+
+        let reward = tax * &BigUint::from(self.referral_share().get()) / BigUint::from(10_000u64);
+        self.referred_mints(referrer).update(|mints| *mints += 1);
+        if reward > BigUint::zero() {
+            self.referral_rewards(referrer, token)
+                .update(|rewards| *rewards += &reward);
+            self.referral_reward_tokens(referrer).insert(token.clone());
+            self.send().direct(referrer, token, nonce, &reward);
+        }
+
+        self.referral_event(referrer, caller, token, &reward);
+        reward
+    }
+
+    // Sends the anti spam tax to the treasuries by their shares, the rounding dust going to the first one
+    fn distribute_anti_spam_tax(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        nonce: u64,
+        tax: &BigUint,
+    ) {
+        let distribution = self.treasury_distribution();
+        if distribution.is_empty() {
+            self.pay_treasury(&self.treasury_address().get(), token, nonce, tax);
+            return;
+        }
+
+        let mut dust = tax.clone();
+        for share in distribution.iter() {
+            dust -= self.get_treasury_share_amount(tax, share.basis_points);
+        }
+
+        for (index, share) in distribution.iter().enumerate() {
+            let mut amount = self.get_treasury_share_amount(tax, share.basis_points);
+            if index == 0 {
+                amount += &dust;
+            }
+            self.pay_treasury(&share.address, token, nonce, &amount);
+        }
+    }
+
+    // Credits the treasury ledger in accumulation mode (fungible tokens only), otherwise sends the amount right away
+    fn pay_treasury(
+        &self,
+        treasury: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        nonce: u64,
+        amount: &BigUint,
+    ) {
+        if amount == &BigUint::zero() {
+            return;
+        }
+        if self.treasury_accumulation_enabled().get() && nonce == 0 {
+            self.treasury_claimable(treasury, token)
+                .update(|claimable| *claimable += amount);
+            self.treasury_claimable_tokens(treasury)
+                .insert(token.clone());
+            self.reserved_funds(token, 0)
+                .update(|reserved| *reserved += amount);
+        } else {
+            self.send().direct_non_zero(treasury, token, nonce, amount);
+        }
+    }
 }
@@ -1,21 +1,26 @@
 use crate::errors::{
-    ERR_FIELD_IS_EMPTY, ERR_MAX_ROYALTIES_TOO_HIGH, ERR_MAX_SUPPLY_EXCEEDED,
-    ERR_MINTING_AND_BURNING_NOT_ALLOWED, ERR_MIN_ROYALTIES_BIGGER_THAN_MAX_ROYALTIES,
-    ERR_NOT_PRIVILEGED, ERR_NOT_URL, ERR_NOT_WHITELISTED, ERR_ONLY_WITHDRAWAL_ADDRESS_CAN_WITHDRAW,
-    ERR_ROYALTIES_ARE_BIGGER_THAN_MAX_ROYALTIES, ERR_ROYALTIES_ARE_SMALLER_THAN_MIN_ROYALTIES,
-    ERR_SUPPLY_HIGHER_THAN_ZERO, ERR_TOKEN_NOT_ISSUED, ERR_TOO_MANY_CHARS,
-    ERR_URL_INVALID_CHARACTERS, ERR_URL_IS_EMPTY, ERR_URL_TOO_BIG, ERR_URL_TOO_SMALL,
-    ERR_VALUE_MUST_BE_POSITIVE, ERR_WAIT_MORE_TIME, ERR_WITHDRAWAL_ADDRESS_NOT_SET,
+    MinterError, ERR_MAX_ROYALTIES_TOO_HIGH, ERR_MIN_ROYALTIES_BIGGER_THAN_MAX_ROYALTIES,
+    ERR_NOT_PRIVILEGED, ERR_ONLY_WITHDRAWAL_ADDRESS_CAN_WITHDRAW, ERR_PAYMENT_TOKEN_NOT_ACCEPTED,
+    ERR_TOKEN_NOT_ISSUED, ERR_VALUE_MUST_BE_POSITIVE, ERR_WITHDRAWAL_ADDRESS_NOT_SET,
 };
+use crate::storage::PromoDiscount;
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 // Module that handles generic (commonly used, which are not specific to one function) requirements which should stop execution and rollback if not met
+// The mint related requirements are also exposed as non-panicking checks, so that they can be evaluated all at once by the validateMint view
 #[multiversx_sc::module]
 pub trait RequirementsModule: crate::storage::StorageModule {
+    // Stops execution with the error of a failed check
+    fn require_check(&self, check: Result<(), MinterError>) {
+        if let Err(error) = check {
+            sc_panic!(error.message());
+        }
+    }
+
     // Checks whether the owner of the smart contract did all the prerequisites for the minting process to start and contract is not paused
-    fn require_ready_for_minting_and_burning(&self) {
+    fn check_ready_for_minting_and_burning(&self) -> Result<(), MinterError> {
         let mut is_mint_ready = true;
         if self.is_paused().get() {
             is_mint_ready = false;
@@ -39,7 +44,14 @@ pub trait RequirementsModule: crate::storage::StorageModule {
         if self.donation_treasury_address().is_empty() {
             is_mint_ready = false;
         }
-        require!(is_mint_ready, ERR_MINTING_AND_BURNING_NOT_ALLOWED);
+        if !is_mint_ready {
+            return Err(MinterError::MintingAndBurningNotAllowed);
+        }
+        Ok(())
+    }
+
+    fn require_ready_for_minting_and_burning(&self) {
+        self.require_check(self.check_ready_for_minting_and_burning());
     }
 
     fn require_withdrawal_address_is_set(&self) {
@@ -49,19 +61,69 @@ pub trait RequirementsModule: crate::storage::StorageModule {
         );
     }
 
-    // Checks whether the address trying to mint is allowed to do so
-    fn require_minting_is_allowed(&self, address: &ManagedAddress, current_time: u64) {
+    // Checks whether the address waited the mint time limit since its last mint
+    fn check_mint_time_limit_passed(
+        &self,
+        address: &ManagedAddress,
+        current_time: u64,
+    ) -> Result<(), MinterError> {
         let last_mint_time = self.last_mint_time(address).get();
         let mint_time_limit = self.mint_time_limit().get();
-        require!(
-            current_time - last_mint_time >= mint_time_limit,
-            ERR_WAIT_MORE_TIME
-        );
+        if current_time - last_mint_time < mint_time_limit {
+            return Err(MinterError::WaitMoreTime);
+        }
+        Ok(())
+    }
 
+    // Checks whether the address is whitelisted, if the whitelist is enabled
+    fn check_is_whitelisted(&self, address: &ManagedAddress) -> Result<(), MinterError> {
         let whitelist_enabled = self.whitelist_enabled().get();
-        if whitelist_enabled {
-            require!(self.whitelist().contains(address), ERR_NOT_WHITELISTED);
+        if whitelist_enabled && !self.whitelist().contains(address) {
+            return Err(MinterError::NotWhitelisted);
+        }
+        Ok(())
+    }
+
+    // Counts the mints of the rolling window of the global mint limit
+    fn count_global_mints(&self, window_sec: u64, current_time: u64) -> u64 {
+        self.global_mint_timestamps()
+            .iter()
+            .filter(|timestamp| timestamp + window_sec > current_time)
+            .count() as u64
+    }
+
+    // Checks whether the global mint limit allows one more mint, exempt addresses bypassing it
+    fn check_global_mint_limit(
+        &self,
+        address: &ManagedAddress,
+        current_time: u64,
+    ) -> Result<(), MinterError> {
+        if self.global_mint_limit().is_empty()
+            || self.rate_limit_exempt_addresses().contains(address)
+        {
+            return Ok(());
         }
+        let global_mint_limit = self.global_mint_limit().get();
+        if self.count_global_mints(global_mint_limit.window_sec, current_time)
+            >= global_mint_limit.max_mints
+        {
+            return Err(MinterError::GlobalMintLimitReached);
+        }
+        Ok(())
+    }
+
+    // Checks whether the address trying to mint is allowed to do so, a mint pass standing in for a whitelist spot
+    fn require_minting_is_allowed(
+        &self,
+        address: &ManagedAddress,
+        current_time: u64,
+        has_mint_pass: bool,
+    ) {
+        self.require_check(self.check_mint_time_limit_passed(address, current_time));
+        if !has_mint_pass {
+            self.require_check(self.check_is_whitelisted(address));
+        }
+        self.require_check(self.check_global_mint_limit(address, current_time));
     }
 
     // Checks whether a value is bigger than zero
@@ -70,20 +132,38 @@ pub trait RequirementsModule: crate::storage::StorageModule {
     }
 
     // Checks whether SFT creation conditions are met
-    fn require_sft_is_valid(&self, royalties: &BigUint, supply: &BigUint) {
+    fn check_sft_is_valid(&self, royalties: &BigUint, supply: &BigUint) -> Result<(), MinterError> {
         let max_royalties = self.max_royalties().get();
         let min_royalties = self.min_royalties().get();
         let max_supply = self.max_supply().get();
-        require!(
-            royalties <= &max_royalties,
-            ERR_ROYALTIES_ARE_BIGGER_THAN_MAX_ROYALTIES
-        );
-        require!(
-            royalties >= &min_royalties,
-            ERR_ROYALTIES_ARE_SMALLER_THAN_MIN_ROYALTIES
-        );
-        require!(supply <= &max_supply, ERR_MAX_SUPPLY_EXCEEDED);
-        require!(supply > &BigUint::zero(), ERR_SUPPLY_HIGHER_THAN_ZERO);
+        if royalties > &max_royalties {
+            return Err(MinterError::RoyaltiesAreBiggerThanMaxRoyalties);
+        }
+        if royalties < &min_royalties {
+            return Err(MinterError::RoyaltiesAreSmallerThanMinRoyalties);
+        }
+        if supply > &max_supply {
+            return Err(MinterError::MaxSupplyExceeded);
+        }
+        if supply == &BigUint::zero() {
+            return Err(MinterError::SupplyHigherThanZero);
+        }
+        Ok(())
+    }
+
+    fn require_sft_is_valid(&self, royalties: &BigUint, supply: &BigUint) {
+        self.require_check(self.check_sft_is_valid(royalties, supply));
+    }
+
+    // Checks whether the donation percentage does not exceed the max donation percentage
+    fn check_donation_percentage_is_valid(
+        &self,
+        donation_percentage: u64,
+    ) -> Result<(), MinterError> {
+        if donation_percentage > self.max_donation_percentage().get() {
+            return Err(MinterError::PercentageTooHigh);
+        }
+        Ok(())
     }
 
     // Checks whether address is privileged
@@ -94,47 +174,78 @@ pub trait RequirementsModule: crate::storage::StorageModule {
         }
     }
 
+    fn check_title_description_are_valid(
+        &self,
+        title: &ManagedBuffer,
+        description: &ManagedBuffer,
+    ) -> Result<(), MinterError> {
+        if title.is_empty() {
+            return Err(MinterError::FieldIsEmpty);
+        }
+        if title.len() > 100 {
+            return Err(MinterError::TooManyChars);
+        }
+        if description.is_empty() {
+            return Err(MinterError::FieldIsEmpty);
+        }
+        if description.len() > 400 {
+            return Err(MinterError::TooManyChars);
+        }
+        Ok(())
+    }
+
     fn require_title_description_are_valid(
         &self,
         title: &ManagedBuffer,
         description: &ManagedBuffer,
     ) {
-        require!(!title.is_empty(), ERR_FIELD_IS_EMPTY);
-        require!(title.len() <= 100, ERR_TOO_MANY_CHARS);
-        require!(!description.is_empty(), ERR_FIELD_IS_EMPTY);
-        require!(description.len() <= 400, ERR_TOO_MANY_CHARS);
+        self.require_check(self.check_title_description_are_valid(title, description));
     }
 
     // Checks whether the URL passed is valid (characters, starts with https://)
-    fn require_url_is_valid(&self, url: &ManagedBuffer) {
-        self.require_url_is_adequate_length(url);
+    fn check_url_is_valid(&self, url: &ManagedBuffer) -> Result<(), MinterError> {
+        self.check_url_is_adequate_length(url)?;
 
         // Define a closure to perform the URL validation
         let validation_closure = |url_bytes: &[u8]| {
             let starts_with: &[u8] = b"https://";
 
-            for i in 0..starts_with.len() {
-                require!(url_bytes[i] == starts_with[i], ERR_NOT_URL);
+            if !url_bytes.starts_with(starts_with) {
+                return Err(MinterError::NotUrl);
             }
 
-            for i in 0..url_bytes.len() {
-                require!(
-                    url_bytes[i] > 32 && url_bytes[i] < 127,
-                    ERR_URL_INVALID_CHARACTERS
-                )
+            if url_bytes.iter().any(|byte| *byte <= 32 || *byte >= 127) {
+                return Err(MinterError::UrlInvalidCharacters);
             }
+
+            Ok(())
         };
 
         // Use the with_buffer_contents function to apply the closure
-        url.with_buffer_contents(validation_closure);
+        url.with_buffer_contents(validation_closure)
+    }
+
+    fn require_url_is_valid(&self, url: &ManagedBuffer) {
+        self.require_check(self.check_url_is_valid(url));
     }
 
     // Checks whether the URL passed has a valid length
-    fn require_url_is_adequate_length(&self, url: &ManagedBuffer) {
+    fn check_url_is_adequate_length(&self, url: &ManagedBuffer) -> Result<(), MinterError> {
         let url_length = url.len();
-        require!(!url.is_empty(), ERR_URL_IS_EMPTY);
-        require!(url_length <= 400, ERR_URL_TOO_BIG);
-        require!(url_length >= 15, ERR_URL_TOO_SMALL);
+        if url.is_empty() {
+            return Err(MinterError::UrlIsEmpty);
+        }
+        if url_length > 400 {
+            return Err(MinterError::UrlTooBig);
+        }
+        if url_length < 15 {
+            return Err(MinterError::UrlTooSmall);
+        }
+        Ok(())
+    }
+
+    fn require_url_is_adequate_length(&self, url: &ManagedBuffer) {
+        self.require_check(self.check_url_is_adequate_length(url));
     }
 
     // Checks whether the royalties passed are valid
@@ -154,6 +265,47 @@ pub trait RequirementsModule: crate::storage::StorageModule {
         require!(!self.token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
     }
 
+    // Checks whether the token is registered as an accepted payment token
+    fn require_payment_token_is_accepted(&self, token: &EgldOrEsdtTokenIdentifier) {
+        require!(
+            self.accepted_payment_tokens().contains(token),
+            ERR_PAYMENT_TOKEN_NOT_ACCEPTED
+        );
+    }
+
+    // Checks whether a promo code can be used to pay with the token, and by the address when one is given
+    fn check_promo_code_is_usable(
+        &self,
+        code_hash: &ManagedByteArray<Self::Api, 32>,
+        address: Option<&ManagedAddress>,
+        token: &EgldOrEsdtTokenIdentifier,
+        current_time: u64,
+    ) -> Result<(), MinterError> {
+        if self.promo_code(code_hash).is_empty() {
+            return Err(MinterError::PromoCodeNotFound);
+        }
+        let promo_code = self.promo_code(code_hash).get();
+        if promo_code.expires_at != 0 && current_time >= promo_code.expires_at {
+            return Err(MinterError::PromoCodeExpired);
+        }
+        if promo_code.max_uses != 0 && promo_code.uses >= promo_code.max_uses {
+            return Err(MinterError::PromoCodeUsageCapReached);
+        }
+        if let Some(address) = address {
+            if promo_code.max_uses_per_address != 0
+                && self.promo_code_uses(code_hash, address).get() >= promo_code.max_uses_per_address
+            {
+                return Err(MinterError::PromoCodeAddressLimitReached);
+            }
+        }
+        if let PromoDiscount::FixedAmount(discount_token, _) = &promo_code.discount {
+            if discount_token != token {
+                return Err(MinterError::PromoCodeNotValidForToken);
+            }
+        }
+        Ok(())
+    }
+
     fn require_is_withdrawal_address(&self, address: &ManagedAddress) {
         require!(
             &self.withdrawal_address().get() == address,
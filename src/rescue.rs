@@ -0,0 +1,116 @@
+use crate::{
+    errors::{
+        ERR_FIELD_IS_EMPTY, ERR_NOT_RESCUE_RECIPIENT, ERR_RESCUE_CLAIM_NOT_FOUND,
+        ERR_WRONG_AMOUNT_OF_FUNDS,
+    },
+    storage::{AuditAction, RescueClaim},
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+// Module that handles returning tokens sent to the contract by mistake, through claims registered by the privileged address and confirmed by their recipient
+#[multiversx_sc::module]
+pub trait RescueModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::requirements::RequirementsModule
+{
+    // Endpoint that will be used by privileged address to register a rescue claim, reserving the tokens for the recipient
+    #[endpoint(registerRescueClaim)]
+    fn register_rescue_claim(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        nonce: u64,
+        amount: BigUint,
+        recipient: ManagedAddress,
+        reason: ManagedBuffer,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        self.require_value_is_positive(&amount);
+        require!(!reason.is_empty(), ERR_FIELD_IS_EMPTY);
+        require!(
+            amount <= self.get_available_balance(&token, nonce),
+            ERR_WRONG_AMOUNT_OF_FUNDS
+        );
+
+        let id = self.rescue_claim_next_id().get();
+        self.rescue_claim_next_id().set(id + 1);
+
+        let claim = RescueClaim {
+            id,
+            token,
+            nonce,
+            amount,
+            recipient,
+            reason,
+        };
+        self.append_audit_entry(AuditAction::RegisterRescueClaim, &claim);
+        self.reserved_funds(&claim.token, claim.nonce)
+            .update(|reserved| *reserved += &claim.amount);
+        self.rescue_claim(id).set(&claim);
+        self.open_rescue_claims().insert(id);
+
+        self.rescue_claim_registered_event(&claim.recipient, id, &claim);
+        id
+    }
+
+    // Endpoint that will be used by privileged address to cancel an open rescue claim, releasing its tokens
+    #[endpoint(cancelRescueClaim)]
+    fn cancel_rescue_claim(&self, id: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        let claim = self.take_open_rescue_claim(id);
+        self.append_audit_entry(AuditAction::CancelRescueClaim, &id);
+
+        self.rescue_claim_cancelled_event(&claim.recipient, id);
+    }
+
+    // Endpoint used by the recipient of a rescue claim to confirm it and receive the tokens
+    #[endpoint(rescueTokens)]
+    fn rescue_tokens(&self, id: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.open_rescue_claims().contains(&id),
+            ERR_RESCUE_CLAIM_NOT_FOUND
+        );
+        require!(
+            self.rescue_claim(id).get().recipient == caller,
+            ERR_NOT_RESCUE_RECIPIENT
+        );
+        let claim = self.take_open_rescue_claim(id);
+
+        self.send()
+            .direct(&claim.recipient, &claim.token, claim.nonce, &claim.amount);
+        self.tokens_rescued_event(
+            &claim.recipient,
+            id,
+            &claim.token,
+            claim.nonce,
+            &claim.amount,
+        );
+    }
+
+    // Removes an open rescue claim and releases the tokens reserved for it
+    fn take_open_rescue_claim(&self, id: u64) -> RescueClaim<Self::Api> {
+        require!(
+            self.open_rescue_claims().swap_remove(&id),
+            ERR_RESCUE_CLAIM_NOT_FOUND
+        );
+        let claim = self.rescue_claim(id).take();
+        self.reserved_funds(&claim.token, claim.nonce)
+            .update(|reserved| *reserved -= &claim.amount);
+        claim
+    }
+
+    // View that returns every rescue claim that was not confirmed or cancelled yet
+    #[view(getOpenRescueClaims)]
+    fn get_open_rescue_claims(&self) -> MultiValueEncoded<RescueClaim<Self::Api>> {
+        let mut claims = MultiValueEncoded::new();
+        for id in self.open_rescue_claims().iter() {
+            claims.push(self.rescue_claim(id).get());
+        }
+        claims
+    }
+}
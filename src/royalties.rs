@@ -0,0 +1,112 @@
+use crate::{
+    errors::{ERR_DATA_NFT_CREATOR_NOT_FOUND, ERR_NOTHING_TO_CLAIM, ERR_PERCENTAGE_TOO_HIGH},
+    storage::{AuditAction, DataNftAttributes},
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+// Module that handles the accounting of the secondary sale royalties received for the Data NFT-FTs, crediting their creators with a share
+#[multiversx_sc::module]
+pub trait RoyaltiesModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::requirements::RequirementsModule
+{
+    // Endpoint used to deposit the royalties of a Data NFT-FT nonce, the creator share being credited to its creator
+    #[payable("*")]
+    #[endpoint(depositRoyalties)]
+    fn deposit_royalties(&self, nonce: u64) {
+        self.require_token_issued();
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_value_is_positive(&amount);
+
+        let creator = self.get_data_nft_creator(nonce);
+
+        let creator_amount =
+            &amount * &BigUint::from(self.creator_royalty_share().get()) / BigUint::from(10_000u64);
+        if creator_amount > BigUint::zero() {
+            self.creator_royalties(&creator, &token)
+                .update(|royalties| *royalties += &creator_amount);
+            self.creator_royalty_tokens(&creator).insert(token.clone());
+            self.reserved_funds(&token, 0)
+                .update(|reserved| *reserved += &creator_amount);
+        }
+
+        self.royalties_deposited_event(
+            &self.blockchain().get_caller(),
+            nonce,
+            &creator,
+            &token,
+            &amount,
+            &creator_amount,
+        );
+    }
+
+    // Endpoint used by a creator to claim the royalties credited to them
+    #[endpoint(claimRoyalties)]
+    fn claim_royalties(&self, tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        let caller = self.blockchain().get_caller();
+        let mut claimed_any = false;
+        for token in tokens.into_iter() {
+            let amount = self.creator_royalties(&caller, &token).take();
+            if amount == BigUint::zero() {
+                continue;
+            }
+            claimed_any = true;
+            self.creator_royalty_tokens(&caller).swap_remove(&token);
+            self.reserved_funds(&token, 0)
+                .update(|reserved| *reserved -= &amount);
+            self.send().direct(&caller, &token, 0, &amount);
+            self.royalties_claimed_event(&caller, &token, &amount);
+        }
+        require!(claimed_any, ERR_NOTHING_TO_CLAIM);
+    }
+
+    // Endpoint that will be used by privileged address to set the share of the royalties credited to creators
+    #[endpoint(setCreatorRoyaltyShare)]
+    fn set_creator_royalty_share(&self, basis_points: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_privileged(&caller);
+        require!(basis_points <= 10_000, ERR_PERCENTAGE_TOO_HIGH);
+        self.append_audit_entry(AuditAction::SetCreatorRoyaltyShare, &basis_points);
+        self.emit_config_changed(
+            b"creatorRoyaltyShare",
+            &self.creator_royalty_share().get(),
+            &basis_points,
+        );
+        self.creator_royalty_share().set(basis_points);
+    }
+
+    // Returns the creator of a nonce, reading it from the token attributes for the nonces minted before creators were tracked
+    // The attributes can only be read while the contract holds some of the nonce, otherwise the creator is unknown
+    fn get_data_nft_creator(&self, nonce: u64) -> ManagedAddress {
+        if !self.data_nft_creator(nonce).is_empty() {
+            return self.data_nft_creator(nonce).get();
+        }
+        let token_id = self.token_id().get_token_id();
+        require!(
+            self.blockchain()
+                .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), nonce)
+                > 0,
+            ERR_DATA_NFT_CREATOR_NOT_FOUND
+        );
+        let attributes: DataNftAttributes<Self::Api> =
+            self.blockchain().get_token_attributes(&token_id, nonce);
+        attributes.creator
+    }
+
+    // View that returns every token and amount of royalties a creator can claim
+    #[view(getCreatorRoyalties)]
+    fn get_creator_royalties(
+        &self,
+        creator: &ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut royalties = MultiValueEncoded::new();
+        for token in self.creator_royalty_tokens(creator).iter() {
+            let amount = self.creator_royalties(creator, &token).get();
+            royalties.push(MultiValue2((token, amount)));
+        }
+        royalties
+    }
+}
@@ -0,0 +1,221 @@
+use crate::errors::{
+    ERR_CREATOR_ALREADY_SPONSORED, ERR_CREATOR_NOT_SPONSORED, ERR_SPONSORED_MINT_LIMIT_REACHED,
+    ERR_SPONSORSHIP_NOT_OFFERED, ERR_SPONSOR_BALANCE_TOO_LOW, ERR_SPONSOR_TOKEN_MISMATCH,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+    TopDecode,
+    TopEncode,
+    NestedDecode,
+    NestedEncode,
+    TypeAbi,
+)]
+pub struct SponsoredCreatorOut<M: ManagedTypeApi> {
+    pub creator: ManagedAddress<M>,
+    pub mint_limit: u64,
+    pub mints: u64,
+}
+
+#[derive(Clone, PartialEq, Debug, TopDecode, TopEncode, NestedDecode, NestedEncode, TypeAbi)]
+pub struct SponsorInfoOut<M: ManagedTypeApi> {
+    pub token: Option<EgldOrEsdtTokenIdentifier<M>>,
+    pub balance: BigUint<M>,
+    pub consumed: BigUint<M>,
+    pub creators: ManagedVec<M, SponsoredCreatorOut<M>>,
+}
+
+// Module that handles sponsors paying the anti spam tax and bond of their creators from a prepaid balance
+// A sponsor only offers to pay for a creator, the creator has to accept the offer before its mints are sponsored
+#[multiversx_sc::module]
+pub trait SponsorshipModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::requirements::RequirementsModule
+    + crate::egld_wrapper_proxy::EgldWrapperProxyMethods
+{
+    // Endpoint used by a sponsor to deposit funds, all deposits of a sponsor being in the same token
+    #[payable("*")]
+    #[endpoint(depositSponsorFunds)]
+    fn deposit_sponsor_funds(&self) {
+        let caller = self.blockchain().get_caller();
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_payment_token_is_accepted(&self.get_anti_spam_tax_token(&token));
+        self.require_value_is_positive(&amount);
+
+        let token_mapper = self.sponsor_token(&caller);
+        require!(
+            token_mapper.is_empty()
+                || token_mapper.get() == token
+                || self.sponsor_balance(&caller).get() == 0,
+            ERR_SPONSOR_TOKEN_MISMATCH
+        );
+        token_mapper.set(&token);
+
+        self.sponsor_balance(&caller)
+            .update(|balance| *balance += &amount);
+        self.reserved_funds(&token, 0)
+            .update(|reserved| *reserved += &amount);
+        self.sponsor_deposit_event(&caller, &token, &amount);
+    }
+
+    // Endpoint used by a sponsor to withdraw part of its remaining balance
+    #[endpoint(withdrawSponsorFunds)]
+    fn withdraw_sponsor_funds(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.require_value_is_positive(&amount);
+        require!(
+            amount <= self.sponsor_balance(&caller).get(),
+            ERR_SPONSOR_BALANCE_TOO_LOW
+        );
+
+        let token = self.sponsor_token(&caller).get();
+        self.sponsor_balance(&caller)
+            .update(|balance| *balance -= &amount);
+        self.reserved_funds(&token, 0)
+            .update(|reserved| *reserved -= &amount);
+
+        self.send().direct(&caller, &token, 0, &amount);
+        self.sponsor_withdraw_event(&caller, &token, &amount);
+    }
+
+    // Endpoint used by a sponsor to offer paying for the mints of creators, with the number of mints it pays for (0 meaning no limit)
+    #[endpoint(addSponsoredCreators)]
+    fn add_sponsored_creators(
+        &self,
+        creators: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        for creator in creators.into_iter() {
+            let (creator, mint_limit) = creator.into_tuple();
+            self.sponsorship_offers(&creator)
+                .insert(caller.clone(), mint_limit);
+            self.sponsorship_offered_event(&caller, &creator, mint_limit);
+        }
+    }
+
+    // Endpoint used by a creator to accept the offer of a sponsor to pay for its mints
+    #[endpoint(acceptSponsor)]
+    fn accept_sponsor(&self, sponsor: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.creator_sponsor(&caller).is_empty(),
+            ERR_CREATOR_ALREADY_SPONSORED
+        );
+        let mint_limit = match self.sponsorship_offers(&caller).remove(&sponsor) {
+            Some(mint_limit) => mint_limit,
+            None => sc_panic!(ERR_SPONSORSHIP_NOT_OFFERED),
+        };
+
+        self.creator_sponsor(&caller).set(&sponsor);
+        self.sponsored_mint_limit(&caller).set(mint_limit);
+        self.sponsored_creators(&sponsor).insert(caller.clone());
+        self.sponsored_creator_added_event(&sponsor, &caller, mint_limit);
+    }
+
+    // Endpoint used by a sponsor to withdraw its offers or stop paying for the mints of creators
+    #[endpoint(removeSponsoredCreators)]
+    fn remove_sponsored_creators(&self, creators: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        for creator in creators.into_iter() {
+            let offer_removed = self.sponsorship_offers(&creator).remove(&caller).is_some();
+            let sponsorship_removed = self.sponsored_creators(&caller).swap_remove(&creator);
+            require!(
+                offer_removed || sponsorship_removed,
+                ERR_CREATOR_NOT_SPONSORED
+            );
+            if sponsorship_removed {
+                self.creator_sponsor(&creator).clear();
+                self.sponsored_mint_limit(&creator).clear();
+                self.sponsored_mints(&creator).clear();
+                self.sponsored_creator_removed_event(&caller, &creator);
+            }
+        }
+    }
+
+    // Returns the sponsor paying for a mint, when a sponsored caller sends no payment
+    fn get_mint_sponsor(
+        &self,
+        caller: &ManagedAddress,
+        payments: &ManagedVec<EgldOrEsdtTokenPayment>,
+    ) -> Option<ManagedAddress> {
+        if !payments.is_empty() || self.creator_sponsor(caller).is_empty() {
+            return None;
+        }
+        Some(self.creator_sponsor(caller).get())
+    }
+
+    // Draws the payment of a mint of the creator from the balance of its sponsor
+    fn consume_sponsor_funds(
+        &self,
+        sponsor: &ManagedAddress,
+        creator: &ManagedAddress,
+        amount: &BigUint,
+    ) -> EgldOrEsdtTokenPayment {
+        let mint_limit = self.sponsored_mint_limit(creator).get();
+        let mints = self.sponsored_mints(creator).get();
+        require!(
+            mint_limit == 0 || mints < mint_limit,
+            ERR_SPONSORED_MINT_LIMIT_REACHED
+        );
+        require!(
+            amount <= &self.sponsor_balance(sponsor).get(),
+            ERR_SPONSOR_BALANCE_TOO_LOW
+        );
+
+        let token = self.sponsor_token(sponsor).get();
+        self.sponsored_mints(creator).set(mints + 1);
+        self.sponsor_balance(sponsor)
+            .update(|balance| *balance -= amount);
+        self.sponsor_consumed(sponsor)
+            .update(|consumed| *consumed += amount);
+        self.reserved_funds(&token, 0)
+            .update(|reserved| *reserved -= amount);
+        self.sponsored_mint_event(sponsor, creator, &token, amount);
+        EgldOrEsdtTokenPayment::new(token, 0, amount.clone())
+    }
+
+    // View that returns the sponsors offering to pay for the mints of a creator, with the mint limit of each offer
+    #[view(getSponsorshipOffers)]
+    fn get_sponsorship_offers(
+        &self,
+        creator: &ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let mut offers = MultiValueEncoded::new();
+        for (sponsor, mint_limit) in self.sponsorship_offers(creator).iter() {
+            offers.push(MultiValue2::from((sponsor, mint_limit)));
+        }
+        offers
+    }
+
+    // View that returns the balance, consumption and creators of a sponsor
+    #[view(getSponsorInfo)]
+    fn get_sponsor_info(&self, sponsor: &ManagedAddress) -> SponsorInfoOut<Self::Api> {
+        let mut creators = ManagedVec::new();
+        for creator in self.sponsored_creators(sponsor).iter() {
+            creators.push(SponsoredCreatorOut {
+                mint_limit: self.sponsored_mint_limit(&creator).get(),
+                mints: self.sponsored_mints(&creator).get(),
+                creator,
+            });
+        }
+
+        let token_mapper = self.sponsor_token(sponsor);
+        SponsorInfoOut {
+            token: if token_mapper.is_empty() {
+                None
+            } else {
+                Some(token_mapper.get())
+            },
+            balance: self.sponsor_balance(sponsor).get(),
+            consumed: self.sponsor_consumed(sponsor).get(),
+            creators,
+        }
+    }
+}
@@ -12,6 +12,146 @@ pub struct DataNftAttributes<M: ManagedTypeApi> {
     pub description: ManagedBuffer<M>,
 }
 
+// Maximum number of audit log entries pruned by a single append, keeping the cost of privileged calls bounded
+pub const MAX_AUDIT_LOG_PRUNE_PER_APPEND: u64 = 10;
+
+// Privileged actions recorded in the audit log
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Debug,
+)]
+pub enum AuditAction {
+    InitializeContract,
+    SetLocalRoles,
+    SetTreasuryAddress,
+    SetDonationTreasuryAddress,
+    SetMaxDonationPercentage,
+    SetIsPaused,
+    SetWhitelistEnabled,
+    SetAntiSpamTax,
+    AddAcceptedPaymentToken,
+    RemoveAcceptedPaymentToken,
+    SetWhitelistSpots,
+    RemoveWhitelistSpots,
+    SetMintTimeLimit,
+    SetRoyaltiesLimits,
+    SetMaxSupply,
+    SetAdministrator,
+    SetBondContractAddress,
+    SetWithdrawalAddress,
+    Withdraw,
+    PauseCollection,
+    UnpauseCollection,
+    FreezeCollection,
+    UnfreezeCollection,
+    FreezeSingleNft,
+    UnfreezeSingleNft,
+    WipeSingleNft,
+    SetAuditLogRetention,
+    SetTreasuryDistribution,
+    SetTreasuryAccumulation,
+    SetCreatorRoyaltyShare,
+    WithdrawMany,
+    RegisterRescueClaim,
+    CancelRescueClaim,
+    AddReferrers,
+    RemoveReferrers,
+    SetReferralShare,
+    RegisterPromoCode,
+    RemovePromoCode,
+    SetBondPaymentToken,
+    SetEgldWrapper,
+    SetAntiSpamTaxPerUnit,
+    SetLockPeriodTaxMultiplier,
+    SetSurgePricing,
+    SetGlobalMintLimit,
+    AddRateLimitExemptions,
+    RemoveRateLimitExemptions,
+    SetFreeMintsPerAddress,
+    SetMintPassToken,
+    DistributeMintPasses,
+    DepositMintPasses,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct AuditEntry<M: ManagedTypeApi> {
+    pub id: u64,
+    pub caller: ManagedAddress<M>,
+    pub timestamp: u64,
+    pub action: AuditAction,
+    pub params_hash: ManagedByteArray<M, 32>,
+}
+
+// Share of the anti spam tax sent to a treasury, in basis points (1% -> 100 ; 100% -> 10000)
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct TreasuryShare<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub basis_points: u64,
+}
+
+// Surge pricing of the anti spam tax, rising by step basis points for each mint of the sliding window above the threshold
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct SurgePricing {
+    pub window_sec: u64,
+    pub threshold: u64,
+    pub step_basis_points: u64,
+    pub max_multiplier: u64,
+}
+
+// Contract wide cap of mints over a rolling window of seconds
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct GlobalMintLimit {
+    pub max_mints: u64,
+    pub window_sec: u64,
+}
+
+// Mints of the current and previous fixed windows, from which the sliding window count is estimated
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug, Default,
+)]
+pub struct SurgeWindow {
+    pub start: u64,
+    pub mints: u64,
+    pub previous_mints: u64,
+}
+
+// Tokens sent to the contract by mistake, to be returned to their recipient once confirmed
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct RescueClaim<M: ManagedTypeApi> {
+    pub id: u64,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub nonce: u64,
+    pub amount: BigUint<M>,
+    pub recipient: ManagedAddress<M>,
+    pub reason: ManagedBuffer<M>,
+}
+
+// Discount granted by a promo code on the anti spam tax, a fixed amount only applying to its token
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub enum PromoDiscount<M: ManagedTypeApi> {
+    BasisPoints(u64),
+    FixedAmount(EgldOrEsdtTokenIdentifier<M>, BigUint<M>),
+}
+
+// Promo code stored under the hash of the code, its limits being ignored when 0
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct PromoCode<M: ManagedTypeApi> {
+    pub discount: PromoDiscount<M>,
+    pub max_uses: u64,
+    pub uses: u64,
+    pub expires_at: u64,
+    pub max_uses_per_address: u64,
+}
+
 // Module that handles the common storage of the smart contract
 #[multiversx_sc::module]
 pub trait StorageModule {
@@ -25,6 +165,62 @@ pub trait StorageModule {
     #[storage_mapper("treasury_address")]
     fn treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    // Stores how the anti spam tax is split between treasuries (when empty, all of it goes to the treasury address)
+    #[view(getTreasuryDistribution)]
+    #[storage_mapper("treasury_distribution")]
+    fn treasury_distribution(&self) -> VecMapper<TreasuryShare<Self::Api>>;
+
+    // Stores whether the anti spam tax is accumulated in the contract for the treasuries to claim instead of being sent on every mint
+    #[view(isTreasuryAccumulationEnabled)]
+    #[storage_mapper("treasury_accumulation_enabled")]
+    fn treasury_accumulation_enabled(&self) -> SingleValueMapper<bool>;
+
+    // Stores the anti spam tax a treasury can claim for a token
+    #[view(getTreasuryClaimableAmount)]
+    #[storage_mapper("treasury_claimable")]
+    fn treasury_claimable(
+        &self,
+        treasury: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    // Stores the tokens a treasury has something to claim for
+    #[storage_mapper("treasury_claimable_tokens")]
+    fn treasury_claimable_tokens(
+        &self,
+        treasury: &ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    // Stores the share of the deposited royalties credited to the Data NFT-FT creator, in basis points
+    #[view(getCreatorRoyaltyShare)]
+    #[storage_mapper("creator_royalty_share")]
+    fn creator_royalty_share(&self) -> SingleValueMapper<u64>;
+
+    // Stores the royalties a creator can claim for a token
+    #[view(getCreatorRoyaltiesAmount)]
+    #[storage_mapper("creator_royalties")]
+    fn creator_royalties(
+        &self,
+        creator: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    // Stores the tokens a creator has royalties to claim for
+    #[storage_mapper("creator_royalty_tokens")]
+    fn creator_royalty_tokens(
+        &self,
+        creator: &ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    // Stores the contract balance owed to someone, which cannot be taken by the withdrawal address
+    #[view(getReservedFunds)]
+    #[storage_mapper("reserved_funds")]
+    fn reserved_funds(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
     #[view(getDonationTreasuryAddress)]
     #[storage_mapper("donation_treasury_address")]
     fn donation_treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
@@ -42,11 +238,65 @@ pub trait StorageModule {
     #[storage_mapper("minted_tokens")]
     fn minted_tokens(&self) -> SingleValueMapper<BigUint>;
 
+    // Stores the version of the contract settings, increased on every setting change
+    #[view(getConfigVersion)]
+    #[storage_mapper("config_version")]
+    fn config_version(&self) -> SingleValueMapper<u64>;
+
+    // Stores the remaining supply of every Data NFT-FT nonce minted since supplies are tracked
+    #[view(getDataNftSupply)]
+    #[storage_mapper("data_nft_supply")]
+    fn data_nft_supply(&self, nonce: u64) -> SingleValueMapper<BigUint>;
+
+    // Stores the creator of every Data NFT-FT nonce minted since creators are tracked, as found in its attributes
+    #[view(getDataNftCreator)]
+    #[storage_mapper("data_nft_creator")]
+    fn data_nft_creator(&self, nonce: u64) -> SingleValueMapper<ManagedAddress>;
+
     // Stores the price for minting an NFT
     #[view(getAntiSpamTax)]
     #[storage_mapper("anti_spam_tax")]
     fn anti_spam_tax(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
+    // Stores the part of the anti spam tax charged for each unit of supply minted
+    #[view(getAntiSpamTaxPerUnit)]
+    #[storage_mapper("anti_spam_tax_per_unit")]
+    fn anti_spam_tax_per_unit(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    // Stores the multipliers in basis points applied to the anti spam tax by lock period
+    #[storage_mapper("lock_period_tax_multipliers")]
+    fn lock_period_tax_multipliers(&self) -> MapMapper<u64, u64>;
+
+    // Stores the surge pricing of the anti spam tax, disabled when empty
+    #[view(getSurgePricing)]
+    #[storage_mapper("surge_pricing")]
+    fn surge_pricing(&self) -> SingleValueMapper<SurgePricing>;
+
+    // Stores the mints counted for the surge pricing
+    #[storage_mapper("surge_window")]
+    fn surge_window(&self) -> SingleValueMapper<SurgeWindow>;
+
+    // Stores the contract wide mint limit, disabled when empty
+    #[view(getGlobalMintLimit)]
+    #[storage_mapper("global_mint_limit")]
+    fn global_mint_limit(&self) -> SingleValueMapper<GlobalMintLimit>;
+
+    // Stores the timestamps of the mints counted for the global mint limit, oldest first
+    #[storage_mapper("global_mint_timestamps")]
+    fn global_mint_timestamps(&self) -> QueueMapper<u64>;
+
+    // Stores the addresses that are not subject to the global mint limit
+    #[view(getRateLimitExemptAddresses)]
+    #[storage_mapper("rate_limit_exempt_addresses")]
+    fn rate_limit_exempt_addresses(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Stores the tokens accepted as payment for the anti spam tax
+    #[storage_mapper("accepted_payment_tokens")]
+    fn accepted_payment_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
     // Stores whether minting is paused or not
     #[view(getIsPaused)]
     #[storage_mapper("is_paused")]
@@ -77,6 +327,11 @@ pub trait StorageModule {
     #[storage_mapper("mint_time_limit")]
     fn mint_time_limit(&self) -> SingleValueMapper<u64>;
 
+    // Stores the number of first mints of every address that carry no anti spam tax
+    #[view(getFreeMintsPerAddress)]
+    #[storage_mapper("free_mints_per_address")]
+    fn free_mints_per_address(&self) -> SingleValueMapper<u64>;
+
     // Stores the moment when an address last minted
     #[view(lastMintTime)]
     #[storage_mapper("last_mint_time")]
@@ -119,4 +374,219 @@ pub trait StorageModule {
     #[view(getBondContractAddress)]
     #[storage_mapper("bond_contract_address")]
     fn bond_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Stores the token of the bond transfer when the anti spam tax is paid in a separate transfer
+    #[view(getBondPaymentToken)]
+    #[storage_mapper("bond_payment_token")]
+    fn bond_payment_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    // Stores the wrapped EGLD token unwrapped when used as payment
+    #[view(getWrappedEgldTokenId)]
+    #[storage_mapper("wrapped_egld_token_id")]
+    fn wrapped_egld_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    // Stores the address of the egld-esdt-swap contract unwrapping the wrapped EGLD payments
+    #[view(getEgldWrapperAddress)]
+    #[storage_mapper("egld_wrapper_address")]
+    fn egld_wrapper_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Stores the referrers whose referred mints are rewarded
+    #[view(getReferrers)]
+    #[storage_mapper("referrers")]
+    fn referrers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Stores the share of the anti spam tax paid to the referrer of a mint, in basis points
+    #[view(getReferralShare)]
+    #[storage_mapper("referral_share")]
+    fn referral_share(&self) -> SingleValueMapper<u64>;
+
+    // Stores how many mints a referrer referred
+    #[view(getReferredMints)]
+    #[storage_mapper("referred_mints")]
+    fn referred_mints(&self, referrer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Stores the rewards a referrer earned for a token
+    #[view(getReferralRewardsAmount)]
+    #[storage_mapper("referral_rewards")]
+    fn referral_rewards(
+        &self,
+        referrer: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    // Stores the tokens a referrer earned rewards in
+    #[storage_mapper("referral_reward_tokens")]
+    fn referral_reward_tokens(
+        &self,
+        referrer: &ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    // Stores the promo codes by the sha256 hash of the code
+    #[view(getPromoCode)]
+    #[storage_mapper("promo_code")]
+    fn promo_code(
+        &self,
+        code_hash: &ManagedByteArray<Self::Api, 32>,
+    ) -> SingleValueMapper<PromoCode<Self::Api>>;
+
+    // Stores how many times an address used a promo code
+    #[view(getPromoCodeUses)]
+    #[storage_mapper("promo_code_uses")]
+    fn promo_code_uses(
+        &self,
+        code_hash: &ManagedByteArray<Self::Api, 32>,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    // Stores the token in which a sponsor deposited its funds
+    #[view(getSponsorToken)]
+    #[storage_mapper("sponsor_token")]
+    fn sponsor_token(
+        &self,
+        sponsor: &ManagedAddress,
+    ) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    // Stores the remaining balance of a sponsor
+    #[view(getSponsorBalance)]
+    #[storage_mapper("sponsor_balance")]
+    fn sponsor_balance(&self, sponsor: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Stores the amount of sponsor funds consumed by the mints of its creators
+    #[view(getSponsorConsumedAmount)]
+    #[storage_mapper("sponsor_consumed")]
+    fn sponsor_consumed(&self, sponsor: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Stores the creators sponsored by a sponsor
+    #[view(getSponsoredCreators)]
+    #[storage_mapper("sponsored_creators")]
+    fn sponsored_creators(&self, sponsor: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Stores the sponsor of a creator
+    #[view(getCreatorSponsor)]
+    #[storage_mapper("creator_sponsor")]
+    fn creator_sponsor(&self, creator: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    // Stores the sponsors offering to pay for the mints of a creator, with the mint limit of each offer
+    #[storage_mapper("sponsorship_offers")]
+    fn sponsorship_offers(&self, creator: &ManagedAddress) -> MapMapper<ManagedAddress, u64>;
+
+    // Stores how many mints of a creator can be sponsored (0 meaning no limit)
+    #[view(getSponsoredMintLimit)]
+    #[storage_mapper("sponsored_mint_limit")]
+    fn sponsored_mint_limit(&self, creator: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Stores how many mints of a creator were sponsored
+    #[view(getSponsoredMints)]
+    #[storage_mapper("sponsored_mints")]
+    fn sponsored_mints(&self, creator: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Stores the prepaid mint credits of an address in a token
+    #[view(getCreditBalance)]
+    #[storage_mapper("credit_balance")]
+    fn credit_balance(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    // Stores the tokens in which an address holds mint credits
+    #[view(getCreditTokens)]
+    #[storage_mapper("credit_tokens")]
+    fn credit_tokens(
+        &self,
+        address: &ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    // Stores the token of the mint passes accepted as proof of eligibility on mint
+    #[storage_mapper("mint_pass_token")]
+    fn mint_pass_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    // Stores whether used mint passes are burned instead of returned to the treasury
+    #[storage_mapper("mint_pass_burn_on_use")]
+    fn mint_pass_burn_on_use(&self) -> SingleValueMapper<bool>;
+
+    // Stores how many mint passes were used
+    #[storage_mapper("mint_passes_used")]
+    fn mint_passes_used(&self) -> SingleValueMapper<u64>;
+
+    // Stores the rescue claims by id
+    #[storage_mapper("rescue_claim")]
+    fn rescue_claim(&self, id: u64) -> SingleValueMapper<RescueClaim<Self::Api>>;
+
+    // Stores the ids of the rescue claims that were not confirmed or cancelled yet
+    #[storage_mapper("open_rescue_claims")]
+    fn open_rescue_claims(&self) -> UnorderedSetMapper<u64>;
+
+    // Stores the id the next rescue claim will get
+    #[storage_mapper("rescue_claim_next_id")]
+    fn rescue_claim_next_id(&self) -> SingleValueMapper<u64>;
+
+    // Stores the audit log entries by id
+    #[storage_mapper("audit_log_entry")]
+    fn audit_log_entry(&self, id: u64) -> SingleValueMapper<AuditEntry<Self::Api>>;
+
+    // Stores the id of the oldest audit log entry that was not pruned
+    #[storage_mapper("audit_log_head")]
+    fn audit_log_head(&self) -> SingleValueMapper<u64>;
+
+    // Stores the id the next audit log entry will get
+    #[storage_mapper("audit_log_next_id")]
+    fn audit_log_next_id(&self) -> SingleValueMapper<u64>;
+
+    // Stores how many audit log entries are kept (0 means all of them)
+    #[view(getAuditLogRetention)]
+    #[storage_mapper("audit_log_retention")]
+    fn audit_log_retention(&self) -> SingleValueMapper<u64>;
+
+    // Top-encodes a value into a buffer
+    fn encode_value<T: TopEncode>(&self, value: &T) -> ManagedBuffer {
+        let mut encoded = ManagedBuffer::new();
+        value
+            .top_encode(&mut encoded)
+            .unwrap_or_else(|err| sc_panic!(err.message_str()));
+        encoded
+    }
+
+    // Returns the contract balance of a token that is not reserved
+    fn get_available_balance(&self, token: &EgldOrEsdtTokenIdentifier, nonce: u64) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(token, nonce);
+        let reserved = self.reserved_funds(token, nonce).get();
+        if balance > reserved {
+            balance - reserved
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    // Appends a privileged action of the caller to the audit log and prunes the entries over the retention
+    fn append_audit_entry<T: TopEncode>(&self, action: AuditAction, params: &T) {
+        let id = self.audit_log_next_id().get();
+        let entry = AuditEntry {
+            id,
+            caller: self.blockchain().get_caller(),
+            timestamp: self.blockchain().get_block_timestamp(),
+            action,
+            params_hash: self.crypto().sha256(&self.encode_value(params)),
+        };
+        self.audit_log_entry(id).set(&entry);
+        self.audit_log_next_id().set(id + 1);
+        self.prune_audit_log();
+    }
+
+    // Removes the oldest audit log entries over the retention, at most MAX_AUDIT_LOG_PRUNE_PER_APPEND at a time
+    fn prune_audit_log(&self) {
+        let retention = self.audit_log_retention().get();
+        if retention == 0 {
+            return;
+        }
+        let next_id = self.audit_log_next_id().get();
+        let mut head = self.audit_log_head().get();
+        let mut pruned = 0u64;
+        while next_id - head > retention && pruned < MAX_AUDIT_LOG_PRUNE_PER_APPEND {
+            self.audit_log_entry(head).clear();
+            head += 1;
+            pruned += 1;
+        }
+        self.audit_log_head().set(head);
+    }
 }
@@ -1,6 +1,14 @@
+use crate::{
+    errors::{MinterError, ERR_WRONG_BOND_PERIOD},
+    storage::{AuditEntry, TreasuryShare},
+};
+
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+// Maximum number of audit log entries returned by one getAuditLog call
+pub const MAX_AUDIT_LOG_PAGE_SIZE: u64 = 100;
+
 #[derive(
     Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, Debug, PartialEq, Eq,
 )]
@@ -19,11 +27,102 @@ pub struct UserDataOut<M: ManagedTypeApi> {
     pub frozen: bool,
     pub frozen_nonces: ManagedVec<M, u64>,
     pub max_donation_percentage: u64,
+    pub surge_multiplier: u64,
+    pub remaining_free_mints: u64,
+}
+
+#[derive(
+    ManagedVecItem,
+    Clone,
+    NestedEncode,
+    NestedDecode,
+    TopEncode,
+    TopDecode,
+    TypeAbi,
+    Debug,
+    PartialEq,
+    Eq,
+)]
+pub struct AntiSpamTaxOut<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub per_unit_amount: BigUint<M>,
+}
+
+#[derive(
+    ManagedVecItem,
+    Clone,
+    NestedEncode,
+    NestedDecode,
+    TopEncode,
+    TopDecode,
+    TypeAbi,
+    Debug,
+    PartialEq,
+    Eq,
+)]
+pub struct ReferralRewardOut<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+#[derive(
+    Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, Debug, PartialEq, Eq,
+)]
+pub struct ReferrerStats<M: ManagedTypeApi> {
+    pub is_registered: bool,
+    pub referred_mints: u64,
+    pub rewards: ManagedVec<M, ReferralRewardOut<M>>,
+}
+
+#[derive(
+    Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, Debug, PartialEq, Eq,
+)]
+pub struct ContractConfig<M: ManagedTypeApi> {
+    pub token_id: Option<TokenIdentifier<M>>,
+    pub anti_spam_taxes: ManagedVec<M, AntiSpamTaxOut<M>>,
+    pub treasury_address: Option<ManagedAddress<M>>,
+    pub treasury_distribution: ManagedVec<M, TreasuryShare<M>>,
+    pub treasury_accumulation_enabled: bool,
+    pub creator_royalty_share: u64,
+    pub donation_treasury_address: Option<ManagedAddress<M>>,
+    pub max_donation_percentage: u64,
+    pub withdrawal_address: Option<ManagedAddress<M>>,
+    pub bond_contract_address: Option<ManagedAddress<M>>,
+    pub bond_payment_token: Option<TokenIdentifier<M>>,
+    pub wrapped_egld_token_id: Option<TokenIdentifier<M>>,
+    pub egld_wrapper_address: Option<ManagedAddress<M>>,
+    pub administrator: Option<ManagedAddress<M>>,
+    pub min_royalties: BigUint<M>,
+    pub max_royalties: BigUint<M>,
+    pub max_supply: BigUint<M>,
+    pub mint_time_limit: u64,
+    pub free_mints_per_address: u64,
+    pub whitelist_enabled: bool,
+    pub is_paused: bool,
+    pub roles_are_set: bool,
+    pub config_version: u64,
+}
+
+#[derive(
+    Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, Debug, PartialEq, Eq,
+)]
+pub struct MintQuote<M: ManagedTypeApi> {
+    pub anti_spam_tax: BigUint<M>,
+    pub bond_amount: BigUint<M>,
+    pub total_payment: BigUint<M>,
+    pub donation_supply: BigUint<M>,
+    pub creator_supply: BigUint<M>,
 }
 
 //Module that handles read-only endpoints (views) for the smart contract
 #[multiversx_sc::module]
-pub trait ViewsModule: crate::storage::StorageModule {
+pub trait ViewsModule:
+    crate::storage::StorageModule
+    + crate::requirements::RequirementsModule
+    + crate::nft_mint_utils::NftMintUtils
+    + crate::bonding_proxy::BondingContractProxyMethods
+{
     // View that returns the above mentioned all-in-one structure for viewing data through one call
     #[view(getUserDataOut)]
     fn get_user_data_out(
@@ -32,7 +131,7 @@ pub trait ViewsModule: crate::storage::StorageModule {
         tax_token: &EgldOrEsdtTokenIdentifier,
     ) -> UserDataOut<Self::Api> {
         {
-            let anti_spam_tax_value = self.anti_spam_tax(tax_token).get(); //if it returns 0 the token is not supported or it requires only bondAmount to be sent
+            let anti_spam_tax_value = self.anti_spam_tax(tax_token).get(); //if it returns 0 the token is either not accepted or it requires only bondAmount to be sent
             let is_paused = self.is_paused().get();
             let max_royalties = self.max_royalties().get();
             let min_royalties = self.min_royalties().get();
@@ -49,6 +148,9 @@ pub trait ViewsModule: crate::storage::StorageModule {
                 .iter()
                 .collect::<ManagedVec<u64>>();
             let max_donation_percentage = self.max_donation_percentage().get();
+            let surge_multiplier =
+                self.get_surge_multiplier(self.blockchain().get_block_timestamp());
+            let remaining_free_mints = self.get_remaining_free_mints(&address);
 
             let user_data = UserDataOut {
                 anti_spam_tax_value,
@@ -65,8 +167,309 @@ pub trait ViewsModule: crate::storage::StorageModule {
                 frozen,
                 frozen_nonces,
                 max_donation_percentage,
+                surge_multiplier,
+                remaining_free_mints,
             };
             user_data
         }
     }
+
+    // View that returns the accepted payment tokens together with their anti spam tax
+    #[view(getAcceptedPaymentTokens)]
+    fn get_accepted_payment_tokens(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token in self.accepted_payment_tokens().iter() {
+            let tax = self.anti_spam_tax(&token).get();
+            result.push(MultiValue2((token, tax)));
+        }
+        result
+    }
+
+    // View that returns the exact payment and supply split of a mint of the caller, also used by the mint endpoint itself
+    #[view(getMintQuote)]
+    fn get_mint_quote(
+        &self,
+        caller: &ManagedAddress,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+        lock_period_sec: u64,
+        supply: &BigUint,
+        donation_percentage: u64,
+    ) -> MintQuote<Self::Api> {
+        // a free mint waives the anti spam tax, only the bond is paid
+        if self.get_remaining_free_mints(caller) > 0 {
+            return self.build_mint_quote(
+                BigUint::zero(),
+                lock_period_sec,
+                supply,
+                donation_percentage,
+            );
+        }
+        self.require_payment_token_is_accepted(payment_token);
+        let anti_spam_tax = self.compute_anti_spam_tax(payment_token, supply, lock_period_sec);
+        self.build_mint_quote(anti_spam_tax, lock_period_sec, supply, donation_percentage)
+    }
+
+    // Builds the payment and supply split of a mint for the given anti spam tax
+    fn build_mint_quote(
+        &self,
+        anti_spam_tax: BigUint,
+        lock_period_sec: u64,
+        supply: &BigUint,
+        donation_percentage: u64,
+    ) -> MintQuote<Self::Api> {
+        let bond_amount = self.get_bond_amount_for_lock_period(lock_period_sec);
+        require!(bond_amount > BigUint::zero(), ERR_WRONG_BOND_PERIOD);
+
+        let total_payment = &anti_spam_tax + &bond_amount;
+
+        let donation_supply = self.get_donation_supply(supply, donation_percentage);
+        let creator_supply = supply - &donation_supply;
+
+        MintQuote {
+            anti_spam_tax,
+            bond_amount,
+            total_payment,
+            donation_supply,
+            creator_supply,
+        }
+    }
+
+    // View that runs every mint requirement without stopping at the first failure and returns the code and message of all the errors that would block the mint
+    #[view(validateMint)]
+    fn validate_mint(
+        &self,
+        caller: ManagedAddress,
+        name: ManagedBuffer,
+        media: ManagedBuffer,
+        metadata: ManagedBuffer,
+        data_marshal: ManagedBuffer,
+        data_stream: ManagedBuffer,
+        data_preview: ManagedBuffer,
+        royalties: BigUint,
+        supply: BigUint,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        lock_period_sec: u64,
+        donation_percentage: u64,
+        extra_assets: MultiValueEncoded<ManagedBuffer>,
+    ) -> MultiValueEncoded<MultiValue2<u32, ManagedBuffer>> {
+        // name and extra assets are taken to mirror the mint arguments, the mint does not validate them
+        let _ = (name, extra_assets);
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let results = [
+            self.check_ready_for_minting_and_burning(),
+            if data_stream.is_empty() {
+                Err(MinterError::DataStreamIsEmpty)
+            } else {
+                Ok(())
+            },
+            self.check_url_is_valid(&data_marshal),
+            self.check_url_is_valid(&data_preview),
+            self.check_url_is_valid(&media),
+            self.check_url_is_valid(&metadata),
+            self.check_title_description_are_valid(&title, &description),
+            self.check_sft_is_valid(&royalties, &supply),
+            self.check_mint_time_limit_passed(&caller, current_time),
+            self.check_is_whitelisted(&caller),
+            self.check_global_mint_limit(&caller, current_time),
+            self.check_donation_percentage_is_valid(donation_percentage),
+            if !self.bond_contract_address().is_empty()
+                && self.get_bond_amount_for_lock_period(lock_period_sec) == BigUint::zero()
+            {
+                Err(MinterError::WrongBondPeriod)
+            } else {
+                Ok(())
+            },
+        ];
+
+        let mut errors = MultiValueEncoded::new();
+        for result in results {
+            if let Err(error) = result {
+                errors.push(MultiValue2((
+                    error.code(),
+                    ManagedBuffer::from(error.message()),
+                )));
+            }
+        }
+
+        errors
+    }
+
+    // View that returns every global setting of the contract through one call
+    #[view(getContractConfig)]
+    fn get_contract_config(&self) -> ContractConfig<Self::Api> {
+        let token_id = if self.token_id().is_empty() {
+            None
+        } else {
+            Some(self.token_id().get_token_id())
+        };
+
+        let mut anti_spam_taxes = ManagedVec::new();
+        for token in self.accepted_payment_tokens().iter() {
+            let amount = self.anti_spam_tax(&token).get();
+            let per_unit_amount = self.anti_spam_tax_per_unit(&token).get();
+            anti_spam_taxes.push(AntiSpamTaxOut {
+                token,
+                amount,
+                per_unit_amount,
+            });
+        }
+
+        ContractConfig {
+            token_id,
+            anti_spam_taxes,
+            treasury_address: self.get_optional_address(self.treasury_address()),
+            treasury_distribution: self.get_treasury_distribution(),
+            treasury_accumulation_enabled: self.treasury_accumulation_enabled().get(),
+            creator_royalty_share: self.creator_royalty_share().get(),
+            donation_treasury_address: self.get_optional_address(self.donation_treasury_address()),
+            max_donation_percentage: self.max_donation_percentage().get(),
+            withdrawal_address: self.get_optional_address(self.withdrawal_address()),
+            bond_contract_address: self.get_optional_address(self.bond_contract_address()),
+            bond_payment_token: if self.bond_payment_token().is_empty() {
+                None
+            } else {
+                Some(self.bond_payment_token().get())
+            },
+            wrapped_egld_token_id: if self.wrapped_egld_token_id().is_empty() {
+                None
+            } else {
+                Some(self.wrapped_egld_token_id().get())
+            },
+            egld_wrapper_address: self.get_optional_address(self.egld_wrapper_address()),
+            administrator: self.get_optional_address(self.administrator()),
+            min_royalties: self.min_royalties().get(),
+            max_royalties: self.max_royalties().get(),
+            max_supply: self.max_supply().get(),
+            mint_time_limit: self.mint_time_limit().get(),
+            free_mints_per_address: self.free_mints_per_address().get(),
+            whitelist_enabled: self.whitelist_enabled().get(),
+            is_paused: self.is_paused().get(),
+            roles_are_set: self.roles_are_set().get(),
+            config_version: self.config_version().get(),
+        }
+    }
+
+    // View that returns the code and message of every error the contract can fail with
+    #[view(getErrorCatalog)]
+    fn get_error_catalog(&self) -> MultiValueEncoded<MultiValue2<u32, ManagedBuffer>> {
+        let mut catalog = MultiValueEncoded::new();
+        for error in MinterError::ALL.iter() {
+            catalog.push(MultiValue2((
+                error.code(),
+                ManagedBuffer::from(error.message()),
+            )));
+        }
+        catalog
+    }
+
+    // View that returns every token and amount a treasury can claim
+    #[view(getTreasuryClaimable)]
+    fn get_treasury_claimable(
+        &self,
+        treasury: &ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut claimable = MultiValueEncoded::new();
+        for token in self.treasury_claimable_tokens(treasury).iter() {
+            let amount = self.treasury_claimable(treasury, &token).get();
+            claimable.push(MultiValue2((token, amount)));
+        }
+        claimable
+    }
+
+    // View that returns whether an address is a registered referrer, how many mints it referred and its rewards per token
+    #[view(getReferrerStats)]
+    fn get_referrer_stats(&self, referrer: &ManagedAddress) -> ReferrerStats<Self::Api> {
+        let mut rewards = ManagedVec::new();
+        for token in self.referral_reward_tokens(referrer).iter() {
+            let amount = self.referral_rewards(referrer, &token).get();
+            rewards.push(ReferralRewardOut { token, amount });
+        }
+        ReferrerStats {
+            is_registered: self.referrers().contains(referrer),
+            referred_mints: self.referred_mints(referrer).get(),
+            rewards,
+        }
+    }
+
+    // View that returns the anti spam tax of a mint once the discount of a promo code is applied
+    #[view(getDiscountedTax)]
+    fn get_discounted_tax_view(
+        &self,
+        code_hash: &ManagedByteArray<Self::Api, 32>,
+        token: &EgldOrEsdtTokenIdentifier,
+        supply: &BigUint,
+        lock_period_sec: u64,
+    ) -> BigUint {
+        self.require_payment_token_is_accepted(token);
+        self.require_check(self.check_promo_code_is_usable(
+            code_hash,
+            None,
+            token,
+            self.blockchain().get_block_timestamp(),
+        ));
+        self.get_discounted_tax(
+            &self.promo_code(code_hash).get(),
+            &self.compute_anti_spam_tax(token, supply, lock_period_sec),
+        )
+    }
+
+    // View that returns the anti spam tax of a mint of the supply locked for the lock period
+    #[view(getComputedAntiSpamTax)]
+    fn get_computed_anti_spam_tax(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        supply: &BigUint,
+        lock_period_sec: u64,
+    ) -> BigUint {
+        self.require_payment_token_is_accepted(token);
+        self.compute_anti_spam_tax(token, supply, lock_period_sec)
+    }
+
+    // View that returns the anti spam tax multipliers in basis points by lock period
+    #[view(getLockPeriodTaxMultipliers)]
+    fn get_lock_period_tax_multipliers(&self) -> MultiValueEncoded<MultiValue2<u64, u64>> {
+        let mut multipliers = MultiValueEncoded::new();
+        for (lock_period_sec, multiplier) in self.lock_period_tax_multipliers().iter() {
+            multipliers.push(MultiValue2((lock_period_sec, multiplier)));
+        }
+        multipliers
+    }
+
+    // View that returns up to limit audit log entries, starting from the entry with the offset id (pruned entries are skipped)
+    #[view(getAuditLog)]
+    fn get_audit_log(&self, offset: u64, limit: u64) -> MultiValueEncoded<AuditEntry<Self::Api>> {
+        let start = core::cmp::max(offset, self.audit_log_head().get());
+        let end = core::cmp::min(
+            start.saturating_add(core::cmp::min(limit, MAX_AUDIT_LOG_PAGE_SIZE)),
+            self.audit_log_next_id().get(),
+        );
+        let mut entries = MultiValueEncoded::new();
+        for id in start..end {
+            entries.push(self.audit_log_entry(id).get());
+        }
+        entries
+    }
+
+    fn get_treasury_distribution(&self) -> ManagedVec<TreasuryShare<Self::Api>> {
+        let mut distribution = ManagedVec::new();
+        for share in self.treasury_distribution().iter() {
+            distribution.push(share);
+        }
+        distribution
+    }
+
+    fn get_optional_address(
+        &self,
+        mapper: SingleValueMapper<ManagedAddress>,
+    ) -> Option<ManagedAddress> {
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
 }
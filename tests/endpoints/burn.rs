@@ -1,8 +1,8 @@
-use datanftmint::storage::DataNftAttributes;
+use datanftmint::storage::{DataNftAttributes, ProxyTrait as _};
 use multiversx_sc_scenario::{
     api::SingleTxApi,
     managed_address, managed_buffer,
-    scenario_model::{CheckAccount, CheckStateStep, SetStateStep, TxExpect},
+    scenario_model::{CheckAccount, CheckStateStep, ScQueryStep, SetStateStep, TxExpect},
 };
 
 use crate::minter_state::minter_state::{
@@ -48,6 +48,12 @@ fn burn_token_test() {
         None,
     );
 
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.minter_contract.data_nft_supply(1u64))
+            .expect(TxExpect::ok().result("5")),
+    );
+
     state.pause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None);
 
     state.minter_burn(
@@ -55,7 +61,9 @@ fn burn_token_test() {
         DATA_NFT_IDENTIFIER,
         1u64,
         2u64,
-        Some(TxExpect::user_error("str:Minting and burning not allowed")),
+        Some(TxExpect::user_error(
+            "str:E2001: Minting and burning not allowed",
+        )),
     );
 
     state.unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None);
@@ -73,7 +81,9 @@ fn burn_token_test() {
         DATA_NFT_IDENTIFIER,
         1u64,
         0u64,
-        Some(TxExpect::user_error("str:Value must be higher than zero")),
+        Some(TxExpect::user_error(
+            "str:E1014: Value must be higher than zero",
+        )),
     );
 
     state.minter_burn(
@@ -84,6 +94,12 @@ fn burn_token_test() {
         None,
     );
 
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.minter_contract.data_nft_supply(1u64))
+            .expect(TxExpect::ok().result("2")),
+    );
+
     let data_nft_attributes: DataNftAttributes<SingleTxApi> = DataNftAttributes {
         data_stream_url: managed_buffer!(b"random-url-encoded-here"),
         data_preview_url: managed_buffer!(b"https://test.com/test"),
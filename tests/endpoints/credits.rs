@@ -0,0 +1,184 @@
+use multiversx_sc_scenario::scenario_model::{
+    CheckAccount, CheckStateStep, SetStateStep, TxExpect,
+};
+
+use crate::minter_state::minter_state::{
+    ContractsState, BONDING_CONTRACT_ADDRESS_EXPR, BONDING_OWNER_ADDRESS_EXPR,
+    EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER, ITHEUM_TOKEN_IDENTIFIER_EXPR,
+    MINTER_OWNER_ADDRESS_EXPR, SECOND_USER_ADDRESS_EXPR, THIRD_USER_ADDRESS_EXPR,
+    TREAASURY_ADDRESS_EXPR, WEGLD_TOKEN_IDENTIFIER, WEGLD_TOKEN_IDENTIFIER_EXPR,
+};
+
+#[test]
+fn mint_with_credits_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_deposit_credits(
+            SECOND_USER_ADDRESS_EXPR,
+            ITHEUM_TOKEN_IDENTIFIER,
+            150u64,
+            None,
+        );
+
+    state.minter_mint_without_payment(
+        SECOND_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        0u64,
+        Some(TxExpect::user_error("str:E3014: Credit balance too low")),
+    );
+
+    state.minter_deposit_credits(
+        SECOND_USER_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        50u64,
+        None,
+    );
+
+    state.minter_mint_without_payment(
+        SECOND_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100"),
+            )
+            .put_account(
+                SECOND_USER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "0"),
+            ),
+    );
+
+    state.minter_withdraw_credits(
+        SECOND_USER_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        1u64,
+        Some(TxExpect::user_error("str:E3014: Credit balance too low")),
+    );
+}
+
+#[test]
+fn mint_with_wrapped_egld_credits_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_set_bond_token(BONDING_OWNER_ADDRESS_EXPR, WEGLD_TOKEN_IDENTIFIER, None)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_add_accepted_payment_token(MINTER_OWNER_ADDRESS_EXPR, b"EGLD", 10u64, None)
+        .egld_wrapper_default_set("1_000")
+        .minter_deposit_credits(
+            THIRD_USER_ADDRESS_EXPR,
+            WEGLD_TOKEN_IDENTIFIER,
+            110u64,
+            Some(TxExpect::user_error(
+                "str:E3004: Payment token not accepted",
+            )),
+        )
+        .minter_set_egld_wrapper(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_deposit_credits(
+            THIRD_USER_ADDRESS_EXPR,
+            WEGLD_TOKEN_IDENTIFIER,
+            110u64,
+            None,
+        );
+
+    // the wrapped EGLD credits are taxed as EGLD and only the anti spam tax is unwrapped
+    state.minter_mint_without_payment(
+        THIRD_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(TREAASURY_ADDRESS_EXPR, CheckAccount::new().balance("10"))
+            .put_account(
+                BONDING_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(WEGLD_TOKEN_IDENTIFIER_EXPR, "100"),
+            )
+            .put_account(
+                EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().balance("990"),
+            ),
+    );
+}
+
+#[test]
+fn withdraw_credits_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .minter_deposit_credits(
+            SECOND_USER_ADDRESS_EXPR,
+            ITHEUM_TOKEN_IDENTIFIER,
+            150u64,
+            None,
+        )
+        .minter_withdraw_credits(
+            SECOND_USER_ADDRESS_EXPR,
+            ITHEUM_TOKEN_IDENTIFIER,
+            100u64,
+            None,
+        );
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            SECOND_USER_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "150"),
+        ));
+}
@@ -1,9 +1,20 @@
-use datanftmint::storage::ProxyTrait as _;
+use datanftmint::{
+    storage::ProxyTrait as _,
+    views::{ContractConfig, ProxyTrait as _},
+    ProxyTrait as _,
+};
 use multiversx_sc::storage::mappers::SingleValue;
-use multiversx_sc_scenario::scenario_model::ScQueryStep;
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    scenario_model::{
+        BytesValue, CheckLog, CheckLogList, CheckLogs, CheckValue, ScCallStep, ScQueryStep,
+        TxExpect,
+    },
+};
 
 use crate::minter_state::minter_state::{
-    ContractsState, MINTER_ADMIN_ADDRESS_EXPR, MINTER_OWNER_ADDRESS_EXPR,
+    ContractsState, MINTER_ADMIN_ADDRESS_EXPR, MINTER_CONTRACT_ADDRESS_EXPR,
+    MINTER_OWNER_ADDRESS_EXPR,
 };
 
 #[test]
@@ -29,11 +40,68 @@ fn deploy_and_upgrade_minter_test() {
             .expect_value(SingleValue::from(false)),
     );
 
+    let config: ContractConfig<StaticApi> = state
+        .world
+        .quick_query(state.minter_contract.get_contract_config());
+    let config_version = config.config_version;
+
     state.minter_upgrade();
 
+    let config: ContractConfig<StaticApi> = state
+        .world
+        .quick_query(state.minter_contract.get_contract_config());
+    assert_eq!(config.config_version, config_version + 1);
+
     state.world.sc_query(
         ScQueryStep::new()
             .call(state.minter_contract.is_paused())
             .expect_value(SingleValue::from(true)),
     );
 }
+
+#[test]
+fn setter_emits_config_changed_test() {
+    let mut state = ContractsState::new();
+    let admin = state.admin.clone();
+
+    state
+        .deploy_minter()
+        .minter_set_administarator(MINTER_OWNER_ADDRESS_EXPR, admin, None);
+
+    let config: ContractConfig<StaticApi> = state
+        .world
+        .quick_query(state.minter_contract.get_contract_config());
+    let config_version = config.config_version + 1;
+
+    // the old and new values are logged top-encoded as the data, true being 0x01 and false empty
+    let mut tx_expect = TxExpect::ok();
+    tx_expect.logs = CheckLogs::List(CheckLogList {
+        list: vec![CheckLog {
+            address: CheckValue::Equal(BytesValue::from(MINTER_CONTRACT_ADDRESS_EXPR)),
+            endpoint: CheckValue::Star,
+            topics: CheckValue::Equal(vec![
+                CheckValue::Equal(BytesValue::from("str:configChanged")),
+                CheckValue::Equal(BytesValue::from("str:isPaused")),
+                CheckValue::Equal(BytesValue::from(MINTER_ADMIN_ADDRESS_EXPR)),
+                CheckValue::Equal(BytesValue::from(config_version.to_string())),
+            ]),
+            data: CheckValue::Equal(vec![CheckValue::Equal(BytesValue::from(
+                "0x000000010100000000",
+            ))]),
+        }],
+        more_allowed_at_end: false,
+    });
+
+    state.world.sc_call(
+        ScCallStep::new()
+            .from(MINTER_ADMIN_ADDRESS_EXPR)
+            .call(state.minter_contract.set_is_paused(false))
+            .expect(tx_expect),
+    );
+
+    let config: ContractConfig<StaticApi> = state
+        .world
+        .quick_query(state.minter_contract.get_contract_config());
+    assert_eq!(config.config_version, config_version);
+    assert_eq!(config.is_paused, false);
+}
@@ -20,7 +20,7 @@ fn initialize_contract_test() {
         10u64,
         treasury_address.clone(),
         Some(1u64),
-        Some(TxExpect::user_error("str:Issue cost is 0.05 eGLD")),
+        Some(TxExpect::user_error("str:E3005: Issue cost is 0.05 eGLD")),
     );
 
     state.minter_initialize_contract(
@@ -46,6 +46,8 @@ fn initialize_contract_test() {
         10u64,
         treasury_address,
         None,
-        Some(TxExpect::user_error("str:Contract was already initialized")),
+        Some(TxExpect::user_error(
+            "str:E4008: Contract was already initialized",
+        )),
     );
 }
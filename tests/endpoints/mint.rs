@@ -1,15 +1,21 @@
-use datanftmint::storage::DataNftAttributes;
+use datanftmint::{
+    nft_mint_utils::MintOptions,
+    storage::{DataNftAttributes, PromoDiscount},
+    views::{MintQuote, ProxyTrait as _, UserDataOut},
+};
 use multiversx_sc_scenario::{
-    api::SingleTxApi,
-    managed_address, managed_buffer,
-    scenario_model::{CheckAccount, CheckStateStep, SetStateStep, TxExpect},
+    api::{SingleTxApi, StaticApi},
+    managed_address, managed_biguint, managed_buffer, managed_token_id_wrapped,
+    scenario_model::{CheckAccount, CheckStateStep, ScCallStep, SetStateStep, TxExpect},
 };
 
 use crate::minter_state::minter_state::{
-    ContractsState, BONDING_CONTRACT_ADDRESS_EXPR, BONDING_OWNER_ADDRESS_EXPR,
-    DATA_NFT_IDENTIFIER_EXPR, FIRST_USER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER,
+    ContractsState, ANOTHER_TOKEN_IDENTIFIER, ANOTHER_TOKEN_IDENTIFIER_EXPR,
+    BONDING_CONTRACT_ADDRESS_EXPR, BONDING_OWNER_ADDRESS_EXPR, DATA_NFT_IDENTIFIER_EXPR,
+    EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR, FIRST_USER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER,
     ITHEUM_TOKEN_IDENTIFIER_EXPR, MINTER_CONTRACT_ADDRESS_EXPR, MINTER_OWNER_ADDRESS_EXPR,
-    SECOND_USER_ADDRESS_EXPR, TREAASURY_ADDRESS_EXPR,
+    SECOND_USER_ADDRESS_EXPR, THIRD_USER_ADDRESS_EXPR, TREAASURY_ADDRESS_EXPR,
+    WEGLD_TOKEN_IDENTIFIER, WEGLD_TOKEN_IDENTIFIER_EXPR, WITHDRAWAL_ADDRESS_EXPR,
 };
 
 #[test]
@@ -35,7 +41,9 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Minting and burning not allowed")),
+        Some(TxExpect::user_error(
+            "str:E2001: Minting and burning not allowed",
+        )),
     );
 
     state
@@ -59,7 +67,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Data Stream is empty")),
+        Some(TxExpect::user_error("str:E1003: Data Stream is empty")),
     );
 
     state.minter_mint(
@@ -79,7 +87,9 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:URL must start with https://")),
+        Some(TxExpect::user_error(
+            "str:E1005: URL must start with https://",
+        )),
     );
 
     state.minter_mint(
@@ -99,7 +109,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:URL is empty")),
+        Some(TxExpect::user_error("str:E1004: URL is empty")),
     );
 
     state.minter_mint(
@@ -119,7 +129,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:URL length is too small")),
+        Some(TxExpect::user_error("str:E1007: URL length is too small")),
     );
 
     state.minter_mint(
@@ -139,7 +149,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:URL length is too big")),
+        Some(TxExpect::user_error("str:E1006: URL length is too big")),
     );
 
     state.minter_mint(
@@ -159,7 +169,9 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:URL must start with https://")),
+        Some(TxExpect::user_error(
+            "str:E1005: URL must start with https://",
+        )),
     );
 
     state.minter_mint(
@@ -179,7 +191,9 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:URL must start with https://")),
+        Some(TxExpect::user_error(
+            "str:E1005: URL must start with https://",
+        )),
     );
 
     state.minter_mint(
@@ -199,7 +213,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Field is empty")),
+        Some(TxExpect::user_error("str:E1001: Field is empty")),
     );
 
     state.minter_mint(
@@ -219,7 +233,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Field is empty")),
+        Some(TxExpect::user_error("str:E1001: Field is empty")),
     );
 
     state.minter_mint(
@@ -239,7 +253,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Too many characters")),
+        Some(TxExpect::user_error("str:E1002: Too many characters")),
     );
 
     state.minter_mint(
@@ -259,7 +273,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Too many characters")),
+        Some(TxExpect::user_error("str:E1002: Too many characters")),
     );
 
     state.minter_mint(
@@ -280,7 +294,7 @@ fn mint_test_without_anti_spam_tax_test() {
         10u64 + 100u64,
         0u64,
         Some(TxExpect::user_error(
-            "str:Royalties are bigger than max royalties",
+            "str:E1009: Royalties are bigger than max royalties",
         )),
     );
 
@@ -301,7 +315,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Max supply exceeded")),
+        Some(TxExpect::user_error("str:E1011: Max supply exceeded")),
     );
 
     state.minter_mint(
@@ -322,7 +336,7 @@ fn mint_test_without_anti_spam_tax_test() {
         10u64 + 100u64,
         0u64,
         Some(TxExpect::user_error(
-            "str:You need to wait more time before minting again",
+            "str:E2003: You need to wait more time before minting again",
         )),
     );
 
@@ -347,7 +361,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:You are not whitelisted")),
+        Some(TxExpect::user_error("str:E2002: You are not whitelisted")),
     );
 
     state.minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
@@ -371,7 +385,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         0u64,
         0u64,
-        Some(TxExpect::user_error("str:Wrong bond period")),
+        Some(TxExpect::user_error("str:E3003: Wrong bond period")),
     );
 
     state.bond_contract_default_deploy_and_set(10u64, 100u64);
@@ -393,7 +407,7 @@ fn mint_test_without_anti_spam_tax_test() {
         0u64,
         10 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Wrong amount of funds")),
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
     );
 
     state.minter_mint(
@@ -469,7 +483,7 @@ fn mint_with_anti_spam_tax_test_and_whitelist() {
         0u64,
         100u64,
         0u64,
-        Some(TxExpect::user_error("str:Wrong amount of funds")),
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
     );
 
     state.minter_mint(
@@ -489,7 +503,7 @@ fn mint_with_anti_spam_tax_test_and_whitelist() {
         0u64,
         99u64 + 100u64,
         0u64,
-        Some(TxExpect::user_error("str:Wrong amount of funds")),
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
     );
 
     state.minter_mint(
@@ -597,7 +611,7 @@ fn mint_with_donation_to_treasury() {
         0u64,
         100u64 + 100u64,
         5_000u64, // 1%
-        Some(TxExpect::user_error("str:Percentage too high")),
+        Some(TxExpect::user_error("str:E1013: Percentage too high")),
     );
 
     state.minter_mint(
@@ -700,3 +714,1305 @@ fn mint_with_donation_to_treasury() {
             ),
         ));
 }
+
+#[test]
+fn mint_with_accepted_payment_tokens_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 0u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_add_accepted_payment_token(
+        MINTER_OWNER_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        10u64,
+        Some(TxExpect::user_error(
+            "str:E4007: Payment token already accepted",
+        )),
+    );
+
+    state.minter_set_anti_spam_tax_token_and_amount(
+        MINTER_OWNER_ADDRESS_EXPR,
+        ANOTHER_TOKEN_IDENTIFIER,
+        1u64,
+        Some(TxExpect::user_error(
+            "str:E3004: Payment token not accepted",
+        )),
+    );
+
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ANOTHER_TOKEN_IDENTIFIER,
+        0u64,
+        5u64,
+        0u64,
+        Some(TxExpect::user_error(
+            "str:E3004: Payment token not accepted",
+        )),
+    );
+
+    state.minter_remove_accepted_payment_token(
+        MINTER_OWNER_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        None,
+    );
+
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64,
+        0u64,
+        Some(TxExpect::user_error(
+            "str:E3004: Payment token not accepted",
+        )),
+    );
+
+    state.minter_add_accepted_payment_token(
+        MINTER_OWNER_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        None,
+    );
+
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64,
+        0u64,
+        None,
+    );
+}
+
+#[test]
+fn mint_with_treasury_distribution_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_set_treasury_distribution(
+        FIRST_USER_ADDRESS_EXPR,
+        vec![(TREAASURY_ADDRESS_EXPR, 10_000u64)],
+        Some(TxExpect::user_error("str:E4001: Address is not privileged")),
+    );
+
+    state.minter_set_treasury_distribution(
+        MINTER_OWNER_ADDRESS_EXPR,
+        vec![
+            (TREAASURY_ADDRESS_EXPR, 3_333u64),
+            (WITHDRAWAL_ADDRESS_EXPR, 6_000u64),
+        ],
+        Some(TxExpect::user_error(
+            "str:E4011: Treasury distribution must sum to 10000 basis points",
+        )),
+    );
+
+    // shares whose sum wraps around to 10000 basis points
+    state.minter_set_treasury_distribution(
+        MINTER_OWNER_ADDRESS_EXPR,
+        vec![
+            (TREAASURY_ADDRESS_EXPR, u64::MAX),
+            (WITHDRAWAL_ADDRESS_EXPR, 10_001u64),
+        ],
+        Some(TxExpect::user_error(
+            "str:E4011: Treasury distribution must sum to 10000 basis points",
+        )),
+    );
+
+    state.minter_set_treasury_distribution(
+        MINTER_OWNER_ADDRESS_EXPR,
+        vec![
+            (TREAASURY_ADDRESS_EXPR, 3_333u64),
+            (WITHDRAWAL_ADDRESS_EXPR, 6_667u64),
+        ],
+        None,
+    );
+
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64 + 100u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "34"),
+            )
+            .put_account(
+                WITHDRAWAL_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "66"),
+            ),
+    );
+}
+
+#[test]
+fn mint_with_referrer_test() {
+    let mut state = ContractsState::new();
+    let second_user_address = state.second_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_set_referral_share(MINTER_OWNER_ADDRESS_EXPR, 2_500u64, None)
+        .minter_add_referrers(
+            MINTER_OWNER_ADDRESS_EXPR,
+            vec![second_user_address.clone()],
+            None,
+        );
+
+    state.minter_add_referrers(
+        MINTER_OWNER_ADDRESS_EXPR,
+        vec![second_user_address.clone()],
+        Some(TxExpect::user_error(
+            "str:E4013: Referrer already registered",
+        )),
+    );
+
+    state.minter_mint_with_options(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64 + 100u64,
+        0u64,
+        MintOptions {
+            referrer: Some(managed_address!(&second_user_address)),
+            promo_code: None,
+            max_payment: None,
+        },
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "75"),
+            )
+            .put_account(
+                SECOND_USER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "225"),
+            ),
+    );
+}
+
+// sha256 of the "ITHEUM50" promo code
+const PROMO_CODE_HASH: [u8; 32] = [
+    0xcf, 0x4e, 0x26, 0xd6, 0x12, 0x83, 0x4d, 0xe4, 0xdd, 0xab, 0x54, 0x3d, 0x8f, 0x44, 0x01, 0xe5,
+    0xf5, 0x60, 0xdb, 0x52, 0x1d, 0xf4, 0x50, 0xb1, 0xab, 0x6c, 0x54, 0x64, 0xb6, 0xea, 0x3c, 0x69,
+];
+
+#[test]
+fn mint_with_promo_code_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_register_promo_code(
+        FIRST_USER_ADDRESS_EXPR,
+        &PROMO_CODE_HASH,
+        PromoDiscount::BasisPoints(5_000u64),
+        2u64,
+        100u64,
+        1u64,
+        Some(TxExpect::user_error("str:E4001: Address is not privileged")),
+    );
+
+    state.minter_register_promo_code(
+        MINTER_OWNER_ADDRESS_EXPR,
+        &PROMO_CODE_HASH,
+        PromoDiscount::BasisPoints(5_000u64),
+        2u64,
+        100u64,
+        1u64,
+        None,
+    );
+
+    let promo_options = || MintOptions {
+        referrer: None,
+        promo_code: Some(managed_buffer!(b"ITHEUM50")),
+        max_payment: None,
+    };
+
+    // paying the full tax while the promo code halves it
+    state.minter_mint_with_options(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64 + 100u64,
+        0u64,
+        promo_options(),
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    state.minter_mint_with_options(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        50u64 + 100u64,
+        0u64,
+        promo_options(),
+        None,
+    );
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            TREAASURY_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "50"),
+        ));
+
+    state.minter_mint_with_options(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        50u64 + 100u64,
+        0u64,
+        promo_options(),
+        Some(TxExpect::user_error(
+            "str:E3009: Promo code limit reached for address",
+        )),
+    );
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(100u64));
+
+    state.minter_mint_with_options(
+        SECOND_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        50u64 + 100u64,
+        0u64,
+        promo_options(),
+        Some(TxExpect::user_error("str:E3007: Promo code expired")),
+    );
+}
+
+#[test]
+fn register_and_remove_promo_code_test() {
+    let mut state = ContractsState::new();
+
+    state.mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64);
+
+    state.minter_remove_promo_code(
+        MINTER_OWNER_ADDRESS_EXPR,
+        &PROMO_CODE_HASH,
+        Some(TxExpect::user_error("str:E3006: Promo code not found")),
+    );
+
+    state.minter_register_promo_code(
+        MINTER_OWNER_ADDRESS_EXPR,
+        &PROMO_CODE_HASH,
+        PromoDiscount::BasisPoints(5_000u64),
+        2u64,
+        100u64,
+        1u64,
+        None,
+    );
+
+    state.minter_register_promo_code(
+        MINTER_OWNER_ADDRESS_EXPR,
+        &PROMO_CODE_HASH,
+        PromoDiscount::BasisPoints(2_000u64),
+        2u64,
+        100u64,
+        1u64,
+        Some(TxExpect::user_error(
+            "str:E4015: Promo code already registered",
+        )),
+    );
+
+    state
+        .minter_remove_promo_code(MINTER_OWNER_ADDRESS_EXPR, &PROMO_CODE_HASH, None)
+        .minter_remove_promo_code(
+            MINTER_OWNER_ADDRESS_EXPR,
+            &PROMO_CODE_HASH,
+            Some(TxExpect::user_error("str:E3006: Promo code not found")),
+        );
+}
+
+#[test]
+fn mint_with_max_payment_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 50u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    let max_payment_options = |max_payment: u64| MintOptions {
+        referrer: None,
+        promo_code: None,
+        max_payment: Some(managed_biguint!(max_payment)),
+    };
+
+    state.minter_mint_with_options(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        140u64,
+        0u64,
+        max_payment_options(140u64),
+        Some(TxExpect::user_error(
+            "str:E3015: Mint price exceeds maximum payment",
+        )),
+    );
+
+    state.minter_mint_with_options(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        190u64,
+        0u64,
+        max_payment_options(180u64),
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    state.minter_mint_with_options(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        180u64,
+        0u64,
+        max_payment_options(200u64),
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                FIRST_USER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "50"),
+            )
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "50"),
+            ),
+    );
+}
+
+#[test]
+fn mint_with_split_payment_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_add_accepted_payment_token(
+            MINTER_OWNER_ADDRESS_EXPR,
+            ANOTHER_TOKEN_IDENTIFIER,
+            3u64,
+            None,
+        );
+
+    state.minter_mint_with_split_payment(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ANOTHER_TOKEN_IDENTIFIER,
+        3u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        100u64,
+        0u64,
+        Some(TxExpect::user_error(
+            "str:E3017: Bond payment token not set",
+        )),
+    );
+
+    state.minter_set_bond_payment_token(MINTER_OWNER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER, None);
+
+    state.minter_mint_with_split_payment(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        100u64,
+        ANOTHER_TOKEN_IDENTIFIER,
+        3u64,
+        0u64,
+        Some(TxExpect::user_error("str:E3016: Wrong bond payment token")),
+    );
+
+    state.minter_mint_with_split_payment(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ANOTHER_TOKEN_IDENTIFIER,
+        3u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        100u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ANOTHER_TOKEN_IDENTIFIER_EXPR, "3"),
+            )
+            .put_account(
+                BONDING_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100"),
+            ),
+    );
+}
+
+#[test]
+fn mint_with_wrapped_egld_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_add_accepted_payment_token(MINTER_OWNER_ADDRESS_EXPR, b"EGLD", 10u64, None)
+        .minter_set_bond_payment_token(MINTER_OWNER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER, None)
+        .egld_wrapper_default_set("1_000");
+
+    state.minter_mint_with_split_payment(
+        THIRD_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        WEGLD_TOKEN_IDENTIFIER,
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        100u64,
+        0u64,
+        Some(TxExpect::user_error(
+            "str:E3004: Payment token not accepted",
+        )),
+    );
+
+    state.minter_set_egld_wrapper(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_mint_with_split_payment(
+        THIRD_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        WEGLD_TOKEN_IDENTIFIER,
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        100u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(TREAASURY_ADDRESS_EXPR, CheckAccount::new().balance("10"))
+            .put_account(
+                THIRD_USER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(WEGLD_TOKEN_IDENTIFIER_EXPR, "190"),
+            )
+            .put_account(
+                EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().balance("990"),
+            ),
+    );
+
+    // outside split mode only the anti spam tax is unwrapped, the bond is sent in wrapped EGLD
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(100u64));
+    state.bond_set_bond_token(BONDING_OWNER_ADDRESS_EXPR, WEGLD_TOKEN_IDENTIFIER, None);
+
+    state.minter_mint(
+        THIRD_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        WEGLD_TOKEN_IDENTIFIER,
+        0u64,
+        110u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(TREAASURY_ADDRESS_EXPR, CheckAccount::new().balance("20"))
+            .put_account(
+                THIRD_USER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(WEGLD_TOKEN_IDENTIFIER_EXPR, "80"),
+            )
+            .put_account(
+                BONDING_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(WEGLD_TOKEN_IDENTIFIER_EXPR, "100"),
+            )
+            .put_account(
+                EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().balance("980"),
+            ),
+    );
+}
+
+#[test]
+fn mint_with_per_unit_tax_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_set_anti_spam_tax_per_unit(
+            MINTER_OWNER_ADDRESS_EXPR,
+            ITHEUM_TOKEN_IDENTIFIER,
+            10u64,
+            None,
+        )
+        .minter_set_lock_period_tax_multiplier(MINTER_OWNER_ADDRESS_EXPR, 10u64, 15_000u64, None);
+
+    // the flat tax is no longer enough for 5 units
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        10u64 + 100u64,
+        0u64,
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    // (10 + 5 * 10) * 1.5 for the 10 seconds lock period
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        90u64 + 100u64,
+        0u64,
+        None,
+    );
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            TREAASURY_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "90"),
+        ));
+}
+
+#[test]
+fn mint_with_surge_pricing_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_set_surge_pricing(
+        MINTER_OWNER_ADDRESS_EXPR,
+        100u64,
+        1u64,
+        5_000u64,
+        9_000u64,
+        Some(TxExpect::user_error("str:E4018: Invalid surge pricing")),
+    );
+
+    state.minter_set_surge_pricing(
+        MINTER_OWNER_ADDRESS_EXPR,
+        100u64,
+        1u64,
+        5_000u64,
+        100_001u64,
+        Some(TxExpect::user_error("str:E4018: Invalid surge pricing")),
+    );
+
+    state.minter_set_surge_pricing(
+        MINTER_OWNER_ADDRESS_EXPR,
+        100u64,
+        1u64,
+        u64::MAX,
+        20_000u64,
+        Some(TxExpect::user_error("str:E4018: Invalid surge pricing")),
+    );
+
+    state.minter_set_surge_pricing(
+        MINTER_OWNER_ADDRESS_EXPR,
+        100u64,
+        1u64,
+        5_000u64,
+        20_000u64,
+        None,
+    );
+
+    for (user, payment) in [
+        (FIRST_USER_ADDRESS_EXPR, 10u64),
+        (SECOND_USER_ADDRESS_EXPR, 10u64),
+        (THIRD_USER_ADDRESS_EXPR, 15u64),
+    ] {
+        state.minter_mint(
+            user,
+            "Test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "random-url-encoded-here",
+            "https://test.com/test",
+            1000u64,
+            5u64,
+            &"Test title".repeat(1),
+            &"Test description".repeat(1),
+            10u64,
+            ITHEUM_TOKEN_IDENTIFIER,
+            0u64,
+            payment + 100u64,
+            0u64,
+            None,
+        );
+    }
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            TREAASURY_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "35"),
+        ));
+
+    let user_data: UserDataOut<StaticApi> =
+        state
+            .world
+            .quick_query(state.minter_contract.get_user_data_out(
+                managed_address!(&first_user_address),
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+            ));
+    assert_eq!(user_data.surge_multiplier, 20_000u64);
+
+    // two quiet windows later the multiplier decays back to 1x
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(300u64));
+
+    let user_data: UserDataOut<StaticApi> =
+        state
+            .world
+            .quick_query(state.minter_contract.get_user_data_out(
+                managed_address!(&first_user_address),
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+            ));
+    assert_eq!(user_data.surge_multiplier, 10_000u64);
+}
+
+#[test]
+fn mint_with_global_mint_limit_test() {
+    let mut state = ContractsState::new();
+    let third_user_address = state.third_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_set_global_mint_limit(
+        MINTER_OWNER_ADDRESS_EXPR,
+        101u64,
+        100u64,
+        Some(TxExpect::user_error("str:E4019: Invalid global mint limit")),
+    );
+
+    state.minter_set_global_mint_limit(MINTER_OWNER_ADDRESS_EXPR, 1u64, 100u64, None);
+
+    for (user, expect) in [
+        (FIRST_USER_ADDRESS_EXPR, None),
+        (
+            SECOND_USER_ADDRESS_EXPR,
+            Some(TxExpect::user_error(
+                "str:E2004: Global mint limit reached, try again later",
+            )),
+        ),
+    ] {
+        state.minter_mint(
+            user,
+            "Test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "random-url-encoded-here",
+            "https://test.com/test",
+            1000u64,
+            5u64,
+            &"Test title".repeat(1),
+            &"Test description".repeat(1),
+            10u64,
+            ITHEUM_TOKEN_IDENTIFIER,
+            0u64,
+            110u64,
+            0u64,
+            expect,
+        );
+    }
+
+    // exempt addresses bypass the limit
+    state.minter_add_rate_limit_exemptions(
+        MINTER_OWNER_ADDRESS_EXPR,
+        vec![third_user_address],
+        None,
+    );
+
+    // once the window rolls over non exempt addresses can mint again
+    for (timestamp, user) in [
+        (11u64, THIRD_USER_ADDRESS_EXPR),
+        (111u64, SECOND_USER_ADDRESS_EXPR),
+    ] {
+        state
+            .world
+            .set_state_step(SetStateStep::new().block_timestamp(timestamp));
+        state.minter_mint(
+            user,
+            "Test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "random-url-encoded-here",
+            "https://test.com/test",
+            1000u64,
+            5u64,
+            &"Test title".repeat(1),
+            &"Test description".repeat(1),
+            10u64,
+            ITHEUM_TOKEN_IDENTIFIER,
+            0u64,
+            110u64,
+            0u64,
+            None,
+        );
+    }
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            TREAASURY_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "30"),
+        ));
+}
+
+#[test]
+fn mint_with_free_mint_allowance_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_set_free_mints_per_address(MINTER_OWNER_ADDRESS_EXPR, 1u64, None);
+
+    let user_data: UserDataOut<StaticApi> =
+        state
+            .world
+            .quick_query(state.minter_contract.get_user_data_out(
+                managed_address!(&first_user_address),
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+            ));
+    assert_eq!(user_data.remaining_free_mints, 1u64);
+
+    // the free mint only requires the bond, the next one the anti spam tax again
+    for (timestamp, expect) in [
+        (11u64, None),
+        (
+            22u64,
+            Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+        ),
+    ] {
+        state
+            .world
+            .set_state_step(SetStateStep::new().block_timestamp(timestamp));
+        state.minter_mint(
+            FIRST_USER_ADDRESS_EXPR,
+            "Test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "random-url-encoded-here",
+            "https://test.com/test",
+            1000u64,
+            5u64,
+            &"Test title".repeat(1),
+            &"Test description".repeat(1),
+            10u64,
+            ITHEUM_TOKEN_IDENTIFIER,
+            0u64,
+            100u64,
+            0u64,
+            expect,
+        );
+    }
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                FIRST_USER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100"),
+            )
+            .put_account(
+                BONDING_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100"),
+            ),
+    );
+
+    let user_data: UserDataOut<StaticApi> =
+        state
+            .world
+            .quick_query(state.minter_contract.get_user_data_out(
+                managed_address!(&first_user_address),
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+            ));
+    assert_eq!(user_data.remaining_free_mints, 0u64);
+}
+
+#[test]
+fn mint_with_free_mint_quote_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 50u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_set_free_mints_per_address(MINTER_OWNER_ADDRESS_EXPR, 1u64, None);
+
+    // the quote waives the anti spam tax while free mints are left, then quotes it again
+    for (timestamp, anti_spam_tax) in [(11u64, 0u64), (22u64, 10u64)] {
+        state
+            .world
+            .set_state_step(SetStateStep::new().block_timestamp(timestamp));
+
+        let quote: MintQuote<StaticApi> =
+            state
+                .world
+                .quick_query(state.minter_contract.get_mint_quote(
+                    managed_address!(&first_user_address),
+                    managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+                    10u64,
+                    managed_biguint!(5u64),
+                    0u64,
+                ));
+        assert_eq!(quote.anti_spam_tax, managed_biguint!(anti_spam_tax));
+        assert_eq!(quote.total_payment, managed_biguint!(anti_spam_tax + 50u64));
+
+        state.minter_mint(
+            FIRST_USER_ADDRESS_EXPR,
+            "Test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "random-url-encoded-here",
+            "https://test.com/test",
+            1000u64,
+            5u64,
+            "Test title",
+            "Test description",
+            10u64,
+            ITHEUM_TOKEN_IDENTIFIER,
+            0u64,
+            quote.total_payment.to_u64().unwrap(),
+            0u64,
+            None,
+        );
+    }
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            FIRST_USER_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "90"),
+        ));
+}
+
+#[test]
+fn mint_with_free_mint_allowance_in_split_mode_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_add_accepted_payment_token(
+            MINTER_OWNER_ADDRESS_EXPR,
+            ANOTHER_TOKEN_IDENTIFIER,
+            3u64,
+            None,
+        )
+        .minter_remove_accepted_payment_token(
+            MINTER_OWNER_ADDRESS_EXPR,
+            ITHEUM_TOKEN_IDENTIFIER,
+            None,
+        )
+        .minter_set_bond_payment_token(MINTER_OWNER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER, None)
+        .minter_set_free_mints_per_address(MINTER_OWNER_ADDRESS_EXPR, 1u64, None);
+
+    // the free mint has no anti spam tax to transfer, the bond is sent alone in the bond payment token
+    for (payment_token, payment_amount, expect) in [
+        (
+            ANOTHER_TOKEN_IDENTIFIER,
+            5u64,
+            Some(TxExpect::user_error("str:E3016: Wrong bond payment token")),
+        ),
+        (ITHEUM_TOKEN_IDENTIFIER, 100u64, None),
+    ] {
+        state.minter_mint(
+            FIRST_USER_ADDRESS_EXPR,
+            "Test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "https://test.com/test",
+            "random-url-encoded-here",
+            "https://test.com/test",
+            1000u64,
+            5u64,
+            &"Test title".repeat(1),
+            &"Test description".repeat(1),
+            10u64,
+            payment_token,
+            0u64,
+            payment_amount,
+            0u64,
+            expect,
+        );
+    }
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                FIRST_USER_ADDRESS_EXPR,
+                CheckAccount::new()
+                    .esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100")
+                    .esdt_balance(ANOTHER_TOKEN_IDENTIFIER_EXPR, "5"),
+            )
+            .put_account(
+                BONDING_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100"),
+            ),
+    );
+}
+
+#[test]
+fn send_bond_is_not_callable_from_outside_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 0u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.world.sc_call(
+        ScCallStep::new()
+            .from(FIRST_USER_ADDRESS_EXPR)
+            .to(MINTER_CONTRACT_ADDRESS_EXPR)
+            .function("send_bond")
+            .argument(FIRST_USER_ADDRESS_EXPR)
+            .argument(DATA_NFT_IDENTIFIER_EXPR)
+            .argument("1")
+            .argument("10")
+            .argument(ITHEUM_TOKEN_IDENTIFIER_EXPR)
+            .argument("0")
+            .argument("100")
+            .expect(TxExpect::err(1, "str:invalid function (not found)")),
+    );
+
+    state.world.sc_call(
+        ScCallStep::new()
+            .from(FIRST_USER_ADDRESS_EXPR)
+            .to(MINTER_CONTRACT_ADDRESS_EXPR)
+            .function("get_bond_amount_for_lock_period")
+            .argument("10")
+            .expect(TxExpect::err(1, "str:invalid function (not found)")),
+    );
+}
@@ -0,0 +1,299 @@
+use datanftmint::mint_pass::{MintPassConfigOut, ProxyTrait as _};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    managed_biguint, managed_token_id,
+    scenario_model::{AddressValue, CheckAccount, CheckStateStep, SetStateStep, TxExpect},
+};
+
+use crate::minter_state::minter_state::{
+    ContractsState, ANOTHER_TOKEN_IDENTIFIER, BONDING_OWNER_ADDRESS_EXPR, FIRST_USER_ADDRESS_EXPR,
+    ITHEUM_TOKEN_IDENTIFIER, ITHEUM_TOKEN_IDENTIFIER_EXPR, MINTER_CONTRACT_ADDRESS_EXPR,
+    MINTER_OWNER_ADDRESS_EXPR, MINT_PASS_TOKEN_IDENTIFIER, MINT_PASS_TOKEN_IDENTIFIER_EXPR,
+    SECOND_USER_ADDRESS_EXPR, TREAASURY_ADDRESS_EXPR, WITHDRAWAL_ADDRESS_EXPR,
+};
+
+#[test]
+fn mint_with_mint_pass_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_set_mint_pass_token(
+        MINTER_OWNER_ADDRESS_EXPR,
+        b"PASS",
+        false,
+        Some(TxExpect::user_error("str:E4021: Invalid mint pass token")),
+    );
+
+    state
+        .minter_set_mint_pass_token(
+            MINTER_OWNER_ADDRESS_EXPR,
+            MINT_PASS_TOKEN_IDENTIFIER,
+            false,
+            None,
+        )
+        .minter_deposit_mint_passes(
+            MINTER_OWNER_ADDRESS_EXPR,
+            MINT_PASS_TOKEN_IDENTIFIER,
+            3u64,
+            None,
+        )
+        .minter_distribute_mint_passes(
+            MINTER_OWNER_ADDRESS_EXPR,
+            vec![(first_user_address, 1u64)],
+            None,
+        );
+
+    // the passes left to distribute can not be withdrawn by the withdrawal address
+    state.set_withdrawal_address(
+        MINTER_OWNER_ADDRESS_EXPR,
+        AddressValue::from(WITHDRAWAL_ADDRESS_EXPR).to_address(),
+        None,
+    );
+    state.minter_withdraw(
+        WITHDRAWAL_ADDRESS_EXPR,
+        MINT_PASS_TOKEN_IDENTIFIER,
+        0u64,
+        1u64,
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    // without a whitelist spot or a mint pass the mint is not allowed
+    state.minter_mint(
+        SECOND_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        110u64,
+        0u64,
+        Some(TxExpect::user_error("str:E2002: You are not whitelisted")),
+    );
+
+    state.minter_mint_with_mint_pass(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        MINT_PASS_TOKEN_IDENTIFIER,
+        ITHEUM_TOKEN_IDENTIFIER,
+        110u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                FIRST_USER_ADDRESS_EXPR,
+                CheckAccount::new()
+                    .esdt_balance(MINT_PASS_TOKEN_IDENTIFIER_EXPR, "0")
+                    .esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "90"),
+            )
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new()
+                    .esdt_balance(MINT_PASS_TOKEN_IDENTIFIER_EXPR, "1")
+                    .esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "10"),
+            )
+            .put_account(
+                MINTER_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(MINT_PASS_TOKEN_IDENTIFIER_EXPR, "2"),
+            ),
+    );
+
+    let config: MintPassConfigOut<StaticApi> = state
+        .world
+        .quick_query(state.minter_contract.get_mint_pass_config());
+    assert_eq!(
+        config,
+        MintPassConfigOut {
+            token: Some(managed_token_id!(MINT_PASS_TOKEN_IDENTIFIER)),
+            burn_on_use: false,
+            available: managed_biguint!(2u64),
+            used: 1u64,
+        }
+    );
+}
+
+#[test]
+fn mint_with_burned_mint_pass_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None);
+
+    // burning used passes requires the local burn role of the token
+    state.minter_set_mint_pass_token(
+        MINTER_OWNER_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        true,
+        Some(TxExpect::user_error(
+            "str:E4023: Missing mint pass burn role",
+        )),
+    );
+
+    state
+        .minter_set_mint_pass_token(
+            MINTER_OWNER_ADDRESS_EXPR,
+            MINT_PASS_TOKEN_IDENTIFIER,
+            true,
+            None,
+        )
+        .minter_deposit_mint_passes(
+            MINTER_OWNER_ADDRESS_EXPR,
+            MINT_PASS_TOKEN_IDENTIFIER,
+            1u64,
+            None,
+        )
+        .minter_distribute_mint_passes(
+            MINTER_OWNER_ADDRESS_EXPR,
+            vec![(first_user_address, 1u64)],
+            None,
+        );
+
+    state.minter_mint_with_mint_pass(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        MINT_PASS_TOKEN_IDENTIFIER,
+        ITHEUM_TOKEN_IDENTIFIER,
+        110u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                FIRST_USER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(MINT_PASS_TOKEN_IDENTIFIER_EXPR, "0"),
+            )
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(MINT_PASS_TOKEN_IDENTIFIER_EXPR, "0"),
+            )
+            .put_account(
+                MINTER_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(MINT_PASS_TOKEN_IDENTIFIER_EXPR, "0"),
+            ),
+    );
+
+    let config: MintPassConfigOut<StaticApi> = state
+        .world
+        .quick_query(state.minter_contract.get_mint_pass_config());
+    assert_eq!(config.used, 1u64);
+}
+
+#[test]
+fn distribute_and_switch_mint_passes_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .minter_set_mint_pass_token(
+            MINTER_OWNER_ADDRESS_EXPR,
+            MINT_PASS_TOKEN_IDENTIFIER,
+            false,
+            None,
+        )
+        .minter_deposit_mint_passes(
+            MINTER_OWNER_ADDRESS_EXPR,
+            MINT_PASS_TOKEN_IDENTIFIER,
+            2u64,
+            None,
+        );
+
+    state.minter_distribute_mint_passes(
+        MINTER_OWNER_ADDRESS_EXPR,
+        vec![(first_user_address.clone(), 3u64)],
+        Some(TxExpect::user_error(
+            "str:E3018: Not enough mint passes deposited",
+        )),
+    );
+
+    // the token can not change while passes of the current one are left to distribute
+    state.minter_set_mint_pass_token(
+        MINTER_OWNER_ADDRESS_EXPR,
+        ANOTHER_TOKEN_IDENTIFIER,
+        false,
+        Some(TxExpect::user_error(
+            "str:E4024: Mint passes of the current token are still reserved",
+        )),
+    );
+
+    let config: MintPassConfigOut<StaticApi> = state
+        .world
+        .quick_query(state.minter_contract.get_mint_pass_config());
+    assert_eq!(config.available, managed_biguint!(2u64));
+
+    state
+        .minter_distribute_mint_passes(
+            MINTER_OWNER_ADDRESS_EXPR,
+            vec![(first_user_address, 2u64)],
+            None,
+        )
+        .minter_set_mint_pass_token(
+            MINTER_OWNER_ADDRESS_EXPR,
+            ANOTHER_TOKEN_IDENTIFIER,
+            false,
+            None,
+        );
+
+    let config: MintPassConfigOut<StaticApi> = state
+        .world
+        .quick_query(state.minter_contract.get_mint_pass_config());
+    assert_eq!(
+        config,
+        MintPassConfigOut {
+            token: Some(managed_token_id!(ANOTHER_TOKEN_IDENTIFIER)),
+            burn_on_use: false,
+            available: managed_biguint!(0u64),
+            used: 0u64,
+        }
+    );
+}
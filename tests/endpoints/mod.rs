@@ -1,7 +1,13 @@
 mod burn;
+mod credits;
 mod deploy_ungrade;
 mod initialize_contract;
 mod mint;
+mod mint_pass;
+mod rescue;
+mod royalties;
+mod sponsorship;
+mod views;
 mod withdraw;
 
 mod collection_management;
@@ -0,0 +1,111 @@
+use multiversx_sc_scenario::scenario_model::{
+    CheckAccount, CheckStateStep, SetStateStep, TransferStep, TxExpect,
+};
+
+use crate::minter_state::minter_state::{
+    ContractsState, BONDING_OWNER_ADDRESS_EXPR, DATA_NFT_IDENTIFIER, DATA_NFT_IDENTIFIER_EXPR,
+    FIRST_USER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER, MINTER_CONTRACT_ADDRESS_EXPR,
+    MINTER_OWNER_ADDRESS_EXPR, SECOND_USER_ADDRESS_EXPR,
+};
+
+#[test]
+fn rescue_tokens_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 0u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64,
+        0u64,
+        None,
+    );
+
+    state.world.transfer_step(
+        TransferStep::new()
+            .from(FIRST_USER_ADDRESS_EXPR)
+            .to(MINTER_CONTRACT_ADDRESS_EXPR)
+            .esdt_transfer(DATA_NFT_IDENTIFIER, 1u64, 2u64),
+    );
+
+    state.minter_register_rescue_claim(
+        SECOND_USER_ADDRESS_EXPR,
+        DATA_NFT_IDENTIFIER,
+        1u64,
+        2u64,
+        first_user_address.clone(),
+        "sent by mistake",
+        Some(TxExpect::user_error("str:E4001: Address is not privileged")),
+    );
+
+    state.minter_register_rescue_claim(
+        MINTER_OWNER_ADDRESS_EXPR,
+        DATA_NFT_IDENTIFIER,
+        1u64,
+        3u64,
+        first_user_address.clone(),
+        "sent by mistake",
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    state.minter_register_rescue_claim(
+        MINTER_OWNER_ADDRESS_EXPR,
+        DATA_NFT_IDENTIFIER,
+        1u64,
+        2u64,
+        first_user_address,
+        "sent by mistake",
+        None,
+    );
+
+    state.minter_rescue_tokens(
+        SECOND_USER_ADDRESS_EXPR,
+        0u64,
+        Some(TxExpect::user_error(
+            "str:E6005: Only the recipient can confirm the rescue claim",
+        )),
+    );
+
+    state.minter_rescue_tokens(FIRST_USER_ADDRESS_EXPR, 0u64, None);
+
+    state.minter_rescue_tokens(
+        FIRST_USER_ADDRESS_EXPR,
+        0u64,
+        Some(TxExpect::user_error("str:E6004: Rescue claim not found")),
+    );
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            FIRST_USER_ADDRESS_EXPR,
+            CheckAccount::new().esdt_nft_balance_and_attributes(
+                DATA_NFT_IDENTIFIER_EXPR,
+                1u64,
+                "5",
+                Option::<&[u8]>::None,
+            ),
+        ));
+}
@@ -0,0 +1,125 @@
+use multiversx_sc_scenario::scenario_model::{
+    AddressValue, CheckAccount, CheckStateStep, SetStateStep, TxExpect,
+};
+
+use crate::minter_state::minter_state::{
+    ContractsState, BONDING_OWNER_ADDRESS_EXPR, FIRST_USER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER,
+    ITHEUM_TOKEN_IDENTIFIER_EXPR, MINTER_OWNER_ADDRESS_EXPR, THIRD_USER_ADDRESS_EXPR,
+    WITHDRAWAL_ADDRESS_EXPR,
+};
+
+#[test]
+fn deposit_and_claim_royalties_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 0u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None);
+
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64,
+        0u64,
+        None,
+    );
+
+    state.minter_set_creator_royalty_share(
+        MINTER_OWNER_ADDRESS_EXPR,
+        10_001u64,
+        Some(TxExpect::user_error("str:E1013: Percentage too high")),
+    );
+
+    state.minter_set_creator_royalty_share(MINTER_OWNER_ADDRESS_EXPR, 6_000u64, None);
+
+    state.minter_deposit_royalties(
+        THIRD_USER_ADDRESS_EXPR,
+        1u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        100u64,
+        None,
+    );
+
+    state.set_withdrawal_address(
+        MINTER_OWNER_ADDRESS_EXPR,
+        AddressValue::from(WITHDRAWAL_ADDRESS_EXPR).to_address(),
+        None,
+    );
+
+    state.minter_withdraw(
+        WITHDRAWAL_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64,
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    state.minter_withdraw(
+        WITHDRAWAL_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        40u64,
+        None,
+    );
+
+    state.minter_claim_royalties(
+        THIRD_USER_ADDRESS_EXPR,
+        vec![ITHEUM_TOKEN_IDENTIFIER],
+        Some(TxExpect::user_error("str:E6003: Nothing to claim")),
+    );
+
+    state.minter_claim_royalties(FIRST_USER_ADDRESS_EXPR, vec![ITHEUM_TOKEN_IDENTIFIER], None);
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            WITHDRAWAL_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "40"),
+        ));
+}
+
+#[test]
+fn deposit_royalties_for_unknown_creator_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 0u64, 10u64)
+        .minter_set_creator_royalty_share(MINTER_OWNER_ADDRESS_EXPR, 6_000u64, None);
+
+    // a nonce without a tracked creator is only resolved through the attributes of a balance held by the minter
+    state.minter_deposit_royalties(
+        THIRD_USER_ADDRESS_EXPR,
+        1u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        100u64,
+        Some(TxExpect::user_error(
+            "str:E6006: Data NFT creator not found",
+        )),
+    );
+
+    state
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            THIRD_USER_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "200"),
+        ));
+}
@@ -0,0 +1,194 @@
+use multiversx_sc_scenario::scenario_model::{
+    AddressValue, CheckAccount, CheckStateStep, SetStateStep, TxExpect,
+};
+
+use crate::minter_state::minter_state::{
+    ContractsState, BONDING_CONTRACT_ADDRESS_EXPR, BONDING_OWNER_ADDRESS_EXPR,
+    EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR, FIRST_USER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER,
+    ITHEUM_TOKEN_IDENTIFIER_EXPR, MINTER_OWNER_ADDRESS_EXPR, THIRD_USER_ADDRESS_EXPR,
+    TREAASURY_ADDRESS_EXPR, WEGLD_TOKEN_IDENTIFIER, WEGLD_TOKEN_IDENTIFIER_EXPR,
+    WITHDRAWAL_ADDRESS_EXPR,
+};
+
+#[test]
+fn sponsored_mint_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+    let second_user_address = state.second_user.clone();
+    let third_user_address = state.third_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_deposit_sponsor_funds(
+            THIRD_USER_ADDRESS_EXPR,
+            ITHEUM_TOKEN_IDENTIFIER,
+            200u64,
+            None,
+        )
+        .minter_add_sponsored_creators(
+            THIRD_USER_ADDRESS_EXPR,
+            vec![(first_user_address.clone(), 1u64)],
+            None,
+        );
+
+    // an offer is not sponsoring the creator until it is accepted
+    state.minter_accept_sponsor(
+        FIRST_USER_ADDRESS_EXPR,
+        second_user_address,
+        Some(TxExpect::user_error("str:E4022: Sponsorship not offered")),
+    );
+    state.minter_accept_sponsor(FIRST_USER_ADDRESS_EXPR, third_user_address, None);
+
+    // a second sponsor can not claim an already sponsored creator
+    state.minter_add_sponsored_creators(
+        MINTER_OWNER_ADDRESS_EXPR,
+        vec![(first_user_address.clone(), 0u64)],
+        None,
+    );
+    state.minter_accept_sponsor(
+        FIRST_USER_ADDRESS_EXPR,
+        AddressValue::from(MINTER_OWNER_ADDRESS_EXPR).to_address(),
+        Some(TxExpect::user_error("str:E4016: Creator already sponsored")),
+    );
+
+    // the reserved sponsor funds can not be withdrawn by the withdrawal address
+    state.set_withdrawal_address(
+        MINTER_OWNER_ADDRESS_EXPR,
+        AddressValue::from(WITHDRAWAL_ADDRESS_EXPR).to_address(),
+        None,
+    );
+    state.minter_withdraw(
+        WITHDRAWAL_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        200u64,
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    state.minter_mint_without_payment(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100"),
+            )
+            .put_account(
+                FIRST_USER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "200"),
+            ),
+    );
+
+    state.minter_mint_without_payment(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        0u64,
+        Some(TxExpect::user_error(
+            "str:E3012: Sponsored mint limit reached",
+        )),
+    );
+
+    state.minter_withdraw_sponsor_funds(
+        THIRD_USER_ADDRESS_EXPR,
+        1u64,
+        Some(TxExpect::user_error("str:E3011: Sponsor balance too low")),
+    );
+}
+
+#[test]
+fn sponsored_mint_with_wrapped_egld_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+    let third_user_address = state.third_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_set_bond_token(BONDING_OWNER_ADDRESS_EXPR, WEGLD_TOKEN_IDENTIFIER, None)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_add_accepted_payment_token(MINTER_OWNER_ADDRESS_EXPR, b"EGLD", 10u64, None)
+        .egld_wrapper_default_set("1_000")
+        .minter_set_egld_wrapper(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_deposit_sponsor_funds(
+            THIRD_USER_ADDRESS_EXPR,
+            WEGLD_TOKEN_IDENTIFIER,
+            110u64,
+            None,
+        )
+        .minter_add_sponsored_creators(
+            THIRD_USER_ADDRESS_EXPR,
+            vec![(first_user_address, 1u64)],
+            None,
+        )
+        .minter_accept_sponsor(FIRST_USER_ADDRESS_EXPR, third_user_address, None);
+
+    // the wrapped EGLD funds are taxed as EGLD and only the anti spam tax is unwrapped
+    state.minter_mint_without_payment(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        0u64,
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(TREAASURY_ADDRESS_EXPR, CheckAccount::new().balance("10"))
+            .put_account(
+                BONDING_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(WEGLD_TOKEN_IDENTIFIER_EXPR, "100"),
+            )
+            .put_account(
+                EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR,
+                CheckAccount::new().balance("990"),
+            ),
+    );
+}
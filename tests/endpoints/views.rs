@@ -0,0 +1,189 @@
+use datanftmint::views::{MintQuote, ProxyTrait as _};
+use multiversx_sc::types::{BigUint, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    managed_address, managed_biguint, managed_token_id_wrapped,
+    scenario_model::{ScQueryStep, SetStateStep, TxExpect},
+};
+
+use crate::minter_state::minter_state::{
+    ContractsState, ANOTHER_TOKEN_IDENTIFIER, BONDING_OWNER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER,
+    MINTER_OWNER_ADDRESS_EXPR,
+};
+
+#[test]
+fn get_mint_quote_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_set_donation_max_percentage(MINTER_OWNER_ADDRESS_EXPR, 1_000, None);
+
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.minter_contract.get_mint_quote(
+                managed_address!(&first_user_address),
+                managed_token_id_wrapped!(ANOTHER_TOKEN_IDENTIFIER),
+                10u64,
+                managed_biguint!(100u64),
+                0u64,
+            ))
+            .expect(TxExpect::user_error(
+                "str:E3004: Payment token not accepted",
+            )),
+    );
+
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.minter_contract.get_mint_quote(
+                managed_address!(&first_user_address),
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+                20u64,
+                managed_biguint!(100u64),
+                0u64,
+            ))
+            .expect(TxExpect::user_error("str:E3003: Wrong bond period")),
+    );
+
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.minter_contract.get_mint_quote(
+                managed_address!(&first_user_address),
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+                10u64,
+                managed_biguint!(100u64),
+                5_000u64,
+            ))
+            .expect(TxExpect::user_error("str:E1013: Percentage too high")),
+    );
+
+    let quote: MintQuote<StaticApi> =
+        state
+            .world
+            .quick_query(state.minter_contract.get_mint_quote(
+                managed_address!(&first_user_address),
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+                10u64,
+                managed_biguint!(100u64),
+                100u64,
+            ));
+
+    assert_eq!(
+        quote,
+        MintQuote {
+            anti_spam_tax: managed_biguint!(100u64),
+            bond_amount: managed_biguint!(100u64),
+            total_payment: managed_biguint!(200u64),
+            donation_supply: managed_biguint!(1u64),
+            creator_supply: managed_biguint!(99u64),
+        }
+    );
+}
+
+#[test]
+fn validate_mint_test() {
+    let mut state = ContractsState::new();
+    let first_user_address = state.first_user.clone();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None);
+
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.minter_contract.validate_mint(
+                managed_address!(&first_user_address),
+                "Test",
+                "https://test.com/test",
+                "https://test.com/test",
+                "https://t.co",
+                "random-url-encoded-here",
+                "https://test.com/test",
+                managed_biguint!(1000u64),
+                managed_biguint!(5u64),
+                "",
+                "Test description",
+                20u64,
+                0u64,
+                MultiValueEncoded::new(),
+            ))
+            .expect(
+                TxExpect::ok()
+                    .result("1007")
+                    .result("str:E1007: URL length is too small")
+                    .result("1001")
+                    .result("str:E1001: Field is empty")
+                    .result("2002")
+                    .result("str:E2002: You are not whitelisted")
+                    .result("3003")
+                    .result("str:E3003: Wrong bond period"),
+            ),
+    );
+
+    state.minter_add_to_whitelist(MINTER_OWNER_ADDRESS_EXPR, first_user_address.clone(), None);
+
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.minter_contract.validate_mint(
+                managed_address!(&first_user_address),
+                "Test",
+                "https://test.com/test",
+                "https://test.com/test",
+                "https://test.com/test",
+                "random-url-encoded-here",
+                "https://test.com/test",
+                managed_biguint!(1000u64),
+                managed_biguint!(5u64),
+                "Test title",
+                "Test description",
+                10u64,
+                0u64,
+                MultiValueEncoded::new(),
+            ))
+            .expect(TxExpect::ok().no_result()),
+    );
+}
+
+#[test]
+fn get_computed_anti_spam_tax_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 10u64, 10u64)
+        .minter_set_anti_spam_tax_per_unit(
+            MINTER_OWNER_ADDRESS_EXPR,
+            ITHEUM_TOKEN_IDENTIFIER,
+            10u64,
+            None,
+        )
+        .minter_set_lock_period_tax_multiplier(MINTER_OWNER_ADDRESS_EXPR, 10u64, 15_000u64, None);
+
+    let tax: BigUint<StaticApi> =
+        state
+            .world
+            .quick_query(state.minter_contract.get_computed_anti_spam_tax(
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+                managed_biguint!(5u64),
+                20u64,
+            ));
+    assert_eq!(tax, managed_biguint!(60u64));
+
+    let tax: BigUint<StaticApi> =
+        state
+            .world
+            .quick_query(state.minter_contract.get_computed_anti_spam_tax(
+                managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER),
+                managed_biguint!(5u64),
+                10u64,
+            ));
+    assert_eq!(tax, managed_biguint!(90u64));
+}
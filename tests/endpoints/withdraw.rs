@@ -1,11 +1,12 @@
 use multiversx_sc_scenario::scenario_model::{
-    AddressValue, CheckAccount, CheckStateStep, TransferStep, TxExpect,
+    AddressValue, CheckAccount, CheckStateStep, SetStateStep, TransferStep, TxExpect,
 };
 
 use crate::minter_state::minter_state::{
-    ContractsState, BONDING_OWNER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER,
+    ContractsState, ANOTHER_TOKEN_IDENTIFIER, ANOTHER_TOKEN_IDENTIFIER_EXPR,
+    BONDING_OWNER_ADDRESS_EXPR, FIRST_USER_ADDRESS_EXPR, ITHEUM_TOKEN_IDENTIFIER,
     ITHEUM_TOKEN_IDENTIFIER_EXPR, MINTER_CONTRACT_ADDRESS_EXPR, MINTER_OWNER_ADDRESS_EXPR,
-    THIRD_USER_ADDRESS_EXPR, WITHDRAWAL_ADDRESS_EXPR,
+    THIRD_USER_ADDRESS_EXPR, TREAASURY_ADDRESS_EXPR, WITHDRAWAL_ADDRESS_EXPR,
 };
 
 #[test]
@@ -30,7 +31,9 @@ fn withdraw_test() {
         ITHEUM_TOKEN_IDENTIFIER,
         0u64,
         100u64,
-        Some(TxExpect::user_error("str:Withdrawal address not set")),
+        Some(TxExpect::user_error(
+            "str:E6001: Withdrawal address not set",
+        )),
     );
 
     state.set_withdrawal_address(
@@ -45,7 +48,7 @@ fn withdraw_test() {
         0u64,
         100u64,
         Some(TxExpect::user_error(
-            "str:Only withdrawal address can withdraw tokens",
+            "str:E6002: Only withdrawal address can withdraw tokens",
         )),
     );
 
@@ -64,3 +67,164 @@ fn withdraw_test() {
             CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100"),
         ));
 }
+
+#[test]
+fn claim_treasury_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .world
+        .set_state_step(SetStateStep::new().block_timestamp(11u64));
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None)
+        .minter_disable_whitelist(MINTER_OWNER_ADDRESS_EXPR, None)
+        .minter_set_treasury_accumulation_enabled(MINTER_OWNER_ADDRESS_EXPR, true, None);
+
+    state.minter_mint(
+        FIRST_USER_ADDRESS_EXPR,
+        "Test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "https://test.com/test",
+        "random-url-encoded-here",
+        "https://test.com/test",
+        1000u64,
+        5u64,
+        &"Test title".repeat(1),
+        &"Test description".repeat(1),
+        10u64,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        100u64 + 100u64,
+        0u64,
+        None,
+    );
+
+    state.world.transfer_step(
+        TransferStep::new()
+            .from(THIRD_USER_ADDRESS_EXPR)
+            .to(MINTER_CONTRACT_ADDRESS_EXPR)
+            .esdt_transfer(ITHEUM_TOKEN_IDENTIFIER, 0u64, 50u64),
+    );
+
+    state.set_withdrawal_address(
+        MINTER_OWNER_ADDRESS_EXPR,
+        AddressValue::from(WITHDRAWAL_ADDRESS_EXPR).to_address(),
+        None,
+    );
+
+    state.minter_withdraw(
+        WITHDRAWAL_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        150u64,
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    state.minter_withdraw(
+        WITHDRAWAL_ADDRESS_EXPR,
+        ITHEUM_TOKEN_IDENTIFIER,
+        0u64,
+        50u64,
+        None,
+    );
+
+    state.minter_claim_treasury(
+        FIRST_USER_ADDRESS_EXPR,
+        vec![ITHEUM_TOKEN_IDENTIFIER],
+        Some(TxExpect::user_error("str:E6003: Nothing to claim")),
+    );
+
+    state.minter_claim_treasury(TREAASURY_ADDRESS_EXPR, vec![ITHEUM_TOKEN_IDENTIFIER], None);
+
+    state.world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                TREAASURY_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100"),
+            )
+            .put_account(
+                WITHDRAWAL_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "50"),
+            ),
+    );
+}
+
+#[test]
+fn withdraw_many_test() {
+    let mut state = ContractsState::new();
+
+    state
+        .mock_minter_initialized(ITHEUM_TOKEN_IDENTIFIER, 100u64, 10u64)
+        .unpause_minter_contract(MINTER_OWNER_ADDRESS_EXPR, None)
+        .bond_contract_default_deploy_and_set(10u64, 100u64)
+        .bond_unpause_contract(BONDING_OWNER_ADDRESS_EXPR, None);
+
+    state.world.transfer_step(
+        TransferStep::new()
+            .from(THIRD_USER_ADDRESS_EXPR)
+            .to(MINTER_CONTRACT_ADDRESS_EXPR)
+            .esdt_transfer(ITHEUM_TOKEN_IDENTIFIER, 0u64, 100u64),
+    );
+
+    state.world.transfer_step(
+        TransferStep::new()
+            .from(FIRST_USER_ADDRESS_EXPR)
+            .to(MINTER_CONTRACT_ADDRESS_EXPR)
+            .esdt_transfer(ANOTHER_TOKEN_IDENTIFIER, 0u64, 5u64),
+    );
+
+    state.world.transfer_step(
+        TransferStep::new()
+            .from(THIRD_USER_ADDRESS_EXPR)
+            .to(MINTER_CONTRACT_ADDRESS_EXPR)
+            .egld_value(50u64),
+    );
+
+    state.set_withdrawal_address(
+        MINTER_OWNER_ADDRESS_EXPR,
+        AddressValue::from(WITHDRAWAL_ADDRESS_EXPR).to_address(),
+        None,
+    );
+
+    state.minter_withdraw_many(
+        MINTER_OWNER_ADDRESS_EXPR,
+        vec![(ITHEUM_TOKEN_IDENTIFIER, 0u64, 0u64)],
+        Some(TxExpect::user_error(
+            "str:E6002: Only withdrawal address can withdraw tokens",
+        )),
+    );
+
+    state.minter_withdraw_many(
+        WITHDRAWAL_ADDRESS_EXPR,
+        vec![
+            (ANOTHER_TOKEN_IDENTIFIER, 0u64, 3u64),
+            (ANOTHER_TOKEN_IDENTIFIER, 0u64, 3u64),
+        ],
+        Some(TxExpect::user_error("str:E3001: Wrong amount of funds")),
+    );
+
+    state.minter_withdraw_many(
+        WITHDRAWAL_ADDRESS_EXPR,
+        vec![
+            (ITHEUM_TOKEN_IDENTIFIER, 0u64, 0u64),
+            (ANOTHER_TOKEN_IDENTIFIER, 0u64, 3u64),
+            (b"EGLD", 0u64, 0u64),
+        ],
+        None,
+    );
+
+    state.world.check_state_step(
+        CheckStateStep::new().put_account(
+            WITHDRAWAL_ADDRESS_EXPR,
+            CheckAccount::new()
+                .balance("50")
+                .esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "100")
+                .esdt_balance(ANOTHER_TOKEN_IDENTIFIER_EXPR, "3"),
+        ),
+    );
+}
@@ -1,8 +1,14 @@
 use core_mx_life_bonding_sc::{admin::ProxyTrait as _, config::ProxyTrait as _, ProxyTrait as _};
-use datanftmint::{collection_management::ProxyTrait as _, ProxyTrait as _};
+use datanftmint::{
+    collection_management::ProxyTrait as _, credits::ProxyTrait as _, mint_pass::ProxyTrait as _,
+    nft_mint_utils::MintOptions, rescue::ProxyTrait as _, royalties::ProxyTrait as _,
+    sponsorship::ProxyTrait as _, storage::PromoDiscount, ProxyTrait as _,
+};
 use multiversx_sc::{
-    codec::multi_types::MultiValue2,
-    types::{Address, MultiValueEncoded},
+    codec::multi_types::{MultiValue2, MultiValue3},
+    types::{
+        Address, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedByteArray, MultiValueEncoded,
+    },
 };
 use multiversx_sc_scenario::{
     api::StaticApi,
@@ -14,6 +20,9 @@ use multiversx_sc_scenario::{
 pub const BONDING_CONTRACT_PATH: &str = "mxsc:output/core_mx_life_bonding_sc.mxsc.json";
 
 pub const MINTER_CONTRACT_PATH: &str = "mxsc:output/datanftmint.mxsc.json";
+
+pub const EGLD_WRAPPER_CONTRACT_PATH: &str = "file:output/multiversx-wegld-swap-sc.wasm";
+pub const EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR: &str = "sc:egld_esdt_swap";
 pub const MINTER_CONTRACT_ADDRESS_EXPR: &str = "sc:datanftmint";
 
 pub const MINTER_OWNER_ADDRESS_EXPR: &str = "address:minter-owner";
@@ -30,6 +39,12 @@ pub const ITHEUM_TOKEN_IDENTIFIER: &[u8] = b"ITHEUM-fce905";
 pub const ANOTHER_TOKEN_IDENTIFIER_EXPR: &str = "str:ANOTHER-fce905";
 pub const ANOTHER_TOKEN_IDENTIFIER: &[u8] = b"ANOTHER-fce905";
 
+pub const WEGLD_TOKEN_IDENTIFIER_EXPR: &str = "str:WEGLD-abcdef";
+pub const WEGLD_TOKEN_IDENTIFIER: &[u8] = b"WEGLD-abcdef";
+
+pub const MINT_PASS_TOKEN_IDENTIFIER_EXPR: &str = "str:PASS-abcdef";
+pub const MINT_PASS_TOKEN_IDENTIFIER: &[u8] = b"PASS-abcdef";
+
 pub const DATA_NFT_IDENTIFIER_EXPR: &str = "str:DATANFT-12345";
 pub const DATA_NFT_IDENTIFIER: &[u8] = b"DATANFT-12345";
 
@@ -57,6 +72,11 @@ pub fn world() -> ScenarioWorld {
         core_mx_life_bonding_sc::ContractBuilder,
     );
 
+    blockchain.register_contract(
+        EGLD_WRAPPER_CONTRACT_PATH,
+        multiversx_wegld_swap_sc::ContractBuilder,
+    );
+
     blockchain
 }
 
@@ -82,7 +102,8 @@ impl ContractsState {
                     Account::new()
                         .nonce(1)
                         .balance("20000000000000000000")
-                        .esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "1_000"),
+                        .esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "1_000")
+                        .esdt_balance(MINT_PASS_TOKEN_IDENTIFIER_EXPR, "10"),
                 )
                 .new_address(MINTER_OWNER_ADDRESS_EXPR, 1, MINTER_CONTRACT_ADDRESS_EXPR)
                 .put_account(
@@ -127,7 +148,8 @@ impl ContractsState {
                     Account::new()
                         .nonce(1)
                         .balance("100")
-                        .esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "200"),
+                        .esdt_balance(ITHEUM_TOKEN_IDENTIFIER_EXPR, "200")
+                        .esdt_balance(WEGLD_TOKEN_IDENTIFIER_EXPR, "200"),
                 )
                 .put_account(WITHDRAWAL_ADDRESS_EXPR, Account::new().nonce(1))
                 .put_account(TREAASURY_ADDRESS_EXPR, Account::new().nonce(1)),
@@ -432,6 +454,175 @@ impl ContractsState {
         self
     }
 
+    pub fn minter_add_accepted_payment_token(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        amount: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let tx_expect = expect.unwrap_or(TxExpect::ok());
+
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.add_accepted_payment_token(
+                    if token_identifier == b"EGLD" {
+                        EgldOrEsdtTokenIdentifier::egld()
+                    } else {
+                        managed_token_id_wrapped!(token_identifier)
+                    },
+                    amount,
+                ))
+                .expect(tx_expect),
+        );
+        self
+    }
+
+    pub fn minter_set_anti_spam_tax_per_unit(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        amount: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.set_anti_spam_tax_per_unit(
+                    managed_token_id_wrapped!(token_identifier),
+                    amount,
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_set_lock_period_tax_multiplier(
+        &mut self,
+        caller: &str,
+        lock_period_sec: u64,
+        basis_points: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(
+                    self.minter_contract
+                        .set_lock_period_tax_multiplier(lock_period_sec, basis_points),
+                )
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_set_surge_pricing(
+        &mut self,
+        caller: &str,
+        window_sec: u64,
+        threshold: u64,
+        step_basis_points: u64,
+        max_multiplier: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.set_surge_pricing(
+                    window_sec,
+                    threshold,
+                    step_basis_points,
+                    max_multiplier,
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_set_global_mint_limit(
+        &mut self,
+        caller: &str,
+        max_mints: u64,
+        window_sec: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(
+                    self.minter_contract
+                        .set_global_mint_limit(max_mints, window_sec),
+                )
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_add_rate_limit_exemptions(
+        &mut self,
+        caller: &str,
+        addresses: Vec<Address>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let mut args = MultiValueEncoded::new();
+        for address in addresses {
+            args.push(managed_address!(&address));
+        }
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.add_rate_limit_exemptions(args))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_remove_accepted_payment_token(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let tx_expect = expect.unwrap_or(TxExpect::ok());
+
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(
+                    self.minter_contract
+                        .remove_accepted_payment_token(managed_token_id_wrapped!(token_identifier)),
+                )
+                .expect(tx_expect),
+        );
+        self
+    }
+
+    pub fn minter_set_treasury_distribution(
+        &mut self,
+        caller: &str,
+        shares: Vec<(&str, u64)>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let tx_expect = expect.unwrap_or(TxExpect::ok());
+        let mut args =
+            MultiValueEncoded::<StaticApi, MultiValue2<ManagedAddress<StaticApi>, u64>>::new();
+        for (address, basis_points) in shares {
+            args.push(MultiValue2((
+                managed_address!(&AddressValue::from(address).to_address()),
+                basis_points,
+            )));
+        }
+
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.set_treasury_distribution(args))
+                .expect(tx_expect),
+        );
+        self
+    }
+
     pub fn minter_add_to_whitelist(
         &mut self,
         caller: &str,
@@ -468,6 +659,37 @@ impl ContractsState {
         self
     }
 
+    pub fn minter_set_bond_payment_token(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(
+                    self.minter_contract
+                        .set_bond_payment_token(managed_token_id!(token_identifier)),
+                )
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_set_egld_wrapper(&mut self, caller: &str, expect: Option<TxExpect>) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.set_egld_wrapper(
+                    managed_token_id!(WEGLD_TOKEN_IDENTIFIER),
+                    AddressValue::from(EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR).to_address(),
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
     pub fn minter_remove_from_whitelist(
         &mut self,
         caller: &str,
@@ -506,6 +728,21 @@ impl ContractsState {
         self
     }
 
+    pub fn minter_set_free_mints_per_address(
+        &mut self,
+        caller: &str,
+        free_mints: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.set_free_mints_per_address(free_mints))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
     pub fn set_withdrawal_address(
         &mut self,
         caller: &str,
@@ -543,125 +780,707 @@ impl ContractsState {
         self
     }
 
-    pub fn minter_burn(
+    pub fn minter_withdraw_many(
         &mut self,
         caller: &str,
-        token_identifier: &[u8],
-        nonce: u64,
-        amount: u64,
+        withdrawals: Vec<(&[u8], u64, u64)>,
         expect: Option<TxExpect>,
     ) -> &mut Self {
+        let mut args = MultiValueEncoded::<StaticApi, _>::new();
+        for (token_identifier, nonce, amount) in withdrawals {
+            let token = if token_identifier == b"EGLD" {
+                EgldOrEsdtTokenIdentifier::egld()
+            } else {
+                managed_token_id_wrapped!(token_identifier)
+            };
+            args.push(MultiValue3((token, nonce, managed_biguint!(amount))));
+        }
+
         self.world.sc_call(
             ScCallStep::new()
                 .from(caller)
-                .esdt_transfer(token_identifier, nonce, amount)
-                .call(self.minter_contract.burn_token())
+                .call(self.minter_contract.withdraw_many(args))
                 .expect(expect.unwrap_or(TxExpect::ok())),
         );
         self
     }
 
-    pub fn minter_mint(
+    pub fn minter_set_treasury_accumulation_enabled(
         &mut self,
         caller: &str,
-        name: &str,
-        media: &str,
-        medatada: &str,
-        data_marshal: &str,
-        data_stream: &str,
-        data_preview: &str,
-        royalties: u64,
-        supply: u64,
-        title: &str,
-        description: &str,
-        lock_period: u64,
-        payment_token_identifier: &[u8],
-        payment_token_nonce: u64,
-        payment_amount: u64,
-        donation_percentage: u64,
+        is_enabled: bool,
         expect: Option<TxExpect>,
     ) -> &mut Self {
         self.world.sc_call(
             ScCallStep::new()
                 .from(caller)
-                .esdt_transfer(
-                    payment_token_identifier,
-                    payment_token_nonce,
-                    payment_amount,
+                .call(
+                    self.minter_contract
+                        .set_treasury_accumulation_enabled(is_enabled),
                 )
-                .call(self.minter_contract.mint_token(
-                    name,
-                    media,
-                    medatada,
-                    data_marshal,
-                    data_stream,
-                    data_preview,
-                    managed_biguint!(royalties),
-                    managed_biguint!(supply),
-                    title,
-                    description,
-                    lock_period,
-                    donation_percentage,
-                    MultiValueEncoded::new(),
-                ))
                 .expect(expect.unwrap_or(TxExpect::ok())),
         );
         self
     }
 
-    pub fn minter_set_local_roles(&mut self, caller: &str, expect: Option<TxExpect>) -> &mut Self {
-        let tx_expect = expect.unwrap_or(TxExpect::ok());
+    pub fn minter_claim_treasury(
+        &mut self,
+        caller: &str,
+        token_identifiers: Vec<&[u8]>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let mut tokens = MultiValueEncoded::<StaticApi, _>::new();
+        for token_identifier in token_identifiers {
+            tokens.push(managed_token_id_wrapped!(token_identifier));
+        }
 
         self.world.sc_call(
             ScCallStep::new()
                 .from(caller)
-                .call(self.minter_contract.set_local_roles())
-                .expect(tx_expect),
+                .call(self.minter_contract.claim_treasury(tokens))
+                .expect(expect.unwrap_or(TxExpect::ok())),
         );
         self
     }
 
-    pub fn minter_initialize_contract(
+    pub fn minter_set_creator_royalty_share(
         &mut self,
         caller: &str,
-        collection_name: &str,
-        token_ticker: &str,
-        anti_spam_tax_token: &[u8],
-        anti_spam_tax_value: u64,
-        mint_time_limit: u64,
-        treasury_address: Address,
-        amount: Option<u64>,
+        basis_points: u64,
         expect: Option<TxExpect>,
     ) -> &mut Self {
         self.world.sc_call(
             ScCallStep::new()
                 .from(caller)
-                .egld_value(amount.unwrap_or(50000000000000000))
-                .call(self.minter_contract.initialize_contract(
-                    collection_name,
-                    token_ticker,
-                    managed_token_id_wrapped!(anti_spam_tax_token),
-                    managed_biguint!(anti_spam_tax_value),
-                    mint_time_limit,
-                    treasury_address,
-                ))
+                .call(self.minter_contract.set_creator_royalty_share(basis_points))
                 .expect(expect.unwrap_or(TxExpect::ok())),
         );
-
         self
     }
 
-    pub fn mock_minter_initialized(
+    pub fn minter_deposit_royalties(
         &mut self,
-        anti_spam_tax_token: &[u8],
-        anti_spam_tax_value: u64,
-        mint_time_limit: u64,
+        caller: &str,
+        nonce: u64,
+        token_identifier: &[u8],
+        amount: u64,
+        expect: Option<TxExpect>,
     ) -> &mut Self {
-        let admin = self.admin.clone();
-        let treasury_address = self.treasury.clone();
-        let minter_code = self.world.code_expression(MINTER_CONTRACT_PATH);
-
-        let mut acc = Account::new()
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(token_identifier, 0u64, amount)
+                .call(self.minter_contract.deposit_royalties(nonce))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_claim_royalties(
+        &mut self,
+        caller: &str,
+        token_identifiers: Vec<&[u8]>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let mut tokens = MultiValueEncoded::<StaticApi, _>::new();
+        for token_identifier in token_identifiers {
+            tokens.push(managed_token_id_wrapped!(token_identifier));
+        }
+
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.claim_royalties(tokens))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_deposit_sponsor_funds(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        amount: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(token_identifier, 0u64, amount)
+                .call(self.minter_contract.deposit_sponsor_funds())
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_withdraw_sponsor_funds(
+        &mut self,
+        caller: &str,
+        amount: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(
+                    self.minter_contract
+                        .withdraw_sponsor_funds(managed_biguint!(amount)),
+                )
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_add_sponsored_creators(
+        &mut self,
+        caller: &str,
+        creators: Vec<(Address, u64)>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let mut args = MultiValueEncoded::<StaticApi, _>::new();
+        for (creator, mint_limit) in creators {
+            args.push(MultiValue2::from((managed_address!(&creator), mint_limit)));
+        }
+
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.add_sponsored_creators(args))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_accept_sponsor(
+        &mut self,
+        caller: &str,
+        sponsor: Address,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(
+                    self.minter_contract
+                        .accept_sponsor(managed_address!(&sponsor)),
+                )
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_set_mint_pass_token(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        burn_on_use: bool,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(
+                    self.minter_contract
+                        .set_mint_pass_token(managed_token_id!(token_identifier), burn_on_use),
+                )
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_deposit_mint_passes(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        amount: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(token_identifier, 0u64, amount)
+                .call(self.minter_contract.deposit_mint_passes())
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_distribute_mint_passes(
+        &mut self,
+        caller: &str,
+        recipients: Vec<(Address, u64)>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let mut args = MultiValueEncoded::<StaticApi, _>::new();
+        for (recipient, amount) in recipients {
+            args.push(MultiValue2::from((
+                managed_address!(&recipient),
+                managed_biguint!(amount),
+            )));
+        }
+
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.distribute_mint_passes(args))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_deposit_credits(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        amount: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(token_identifier, 0u64, amount)
+                .call(self.minter_contract.deposit_credits())
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_withdraw_credits(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        amount: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.withdraw_credits(
+                    managed_token_id_wrapped!(token_identifier),
+                    managed_biguint!(amount),
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_register_rescue_claim(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        nonce: u64,
+        amount: u64,
+        recipient: Address,
+        reason: &str,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.register_rescue_claim(
+                    managed_token_id_wrapped!(token_identifier),
+                    nonce,
+                    managed_biguint!(amount),
+                    recipient,
+                    reason,
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_rescue_tokens(
+        &mut self,
+        caller: &str,
+        id: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.rescue_tokens(id))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_burn(
+        &mut self,
+        caller: &str,
+        token_identifier: &[u8],
+        nonce: u64,
+        amount: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(token_identifier, nonce, amount)
+                .call(self.minter_contract.burn_token())
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_mint(
+        &mut self,
+        caller: &str,
+        name: &str,
+        media: &str,
+        medatada: &str,
+        data_marshal: &str,
+        data_stream: &str,
+        data_preview: &str,
+        royalties: u64,
+        supply: u64,
+        title: &str,
+        description: &str,
+        lock_period: u64,
+        payment_token_identifier: &[u8],
+        payment_token_nonce: u64,
+        payment_amount: u64,
+        donation_percentage: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(
+                    payment_token_identifier,
+                    payment_token_nonce,
+                    payment_amount,
+                )
+                .call(self.minter_contract.mint_token(
+                    name,
+                    media,
+                    medatada,
+                    data_marshal,
+                    data_stream,
+                    data_preview,
+                    managed_biguint!(royalties),
+                    managed_biguint!(supply),
+                    title,
+                    description,
+                    lock_period,
+                    donation_percentage,
+                    MultiValueEncoded::new(),
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_mint_with_split_payment(
+        &mut self,
+        caller: &str,
+        name: &str,
+        media: &str,
+        medatada: &str,
+        data_marshal: &str,
+        data_stream: &str,
+        data_preview: &str,
+        royalties: u64,
+        supply: u64,
+        title: &str,
+        description: &str,
+        lock_period: u64,
+        tax_token_identifier: &[u8],
+        tax_amount: u64,
+        bond_token_identifier: &[u8],
+        bond_amount: u64,
+        donation_percentage: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(tax_token_identifier, 0u64, tax_amount)
+                .esdt_transfer(bond_token_identifier, 0u64, bond_amount)
+                .call(self.minter_contract.mint_token(
+                    name,
+                    media,
+                    medatada,
+                    data_marshal,
+                    data_stream,
+                    data_preview,
+                    managed_biguint!(royalties),
+                    managed_biguint!(supply),
+                    title,
+                    description,
+                    lock_period,
+                    donation_percentage,
+                    MultiValueEncoded::new(),
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_mint_with_mint_pass(
+        &mut self,
+        caller: &str,
+        name: &str,
+        media: &str,
+        medatada: &str,
+        data_marshal: &str,
+        data_stream: &str,
+        data_preview: &str,
+        royalties: u64,
+        supply: u64,
+        title: &str,
+        description: &str,
+        lock_period: u64,
+        mint_pass_token_identifier: &[u8],
+        payment_token_identifier: &[u8],
+        payment_amount: u64,
+        donation_percentage: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(mint_pass_token_identifier, 0u64, 1u64)
+                .esdt_transfer(payment_token_identifier, 0u64, payment_amount)
+                .call(self.minter_contract.mint_token(
+                    name,
+                    media,
+                    medatada,
+                    data_marshal,
+                    data_stream,
+                    data_preview,
+                    managed_biguint!(royalties),
+                    managed_biguint!(supply),
+                    title,
+                    description,
+                    lock_period,
+                    donation_percentage,
+                    MultiValueEncoded::new(),
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_mint_without_payment(
+        &mut self,
+        caller: &str,
+        name: &str,
+        media: &str,
+        medatada: &str,
+        data_marshal: &str,
+        data_stream: &str,
+        data_preview: &str,
+        royalties: u64,
+        supply: u64,
+        title: &str,
+        description: &str,
+        lock_period: u64,
+        donation_percentage: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.mint_token(
+                    name,
+                    media,
+                    medatada,
+                    data_marshal,
+                    data_stream,
+                    data_preview,
+                    managed_biguint!(royalties),
+                    managed_biguint!(supply),
+                    title,
+                    description,
+                    lock_period,
+                    donation_percentage,
+                    MultiValueEncoded::new(),
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_mint_with_options(
+        &mut self,
+        caller: &str,
+        name: &str,
+        media: &str,
+        medatada: &str,
+        data_marshal: &str,
+        data_stream: &str,
+        data_preview: &str,
+        royalties: u64,
+        supply: u64,
+        title: &str,
+        description: &str,
+        lock_period: u64,
+        payment_token_identifier: &[u8],
+        payment_token_nonce: u64,
+        payment_amount: u64,
+        donation_percentage: u64,
+        options: MintOptions<StaticApi>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(
+                    payment_token_identifier,
+                    payment_token_nonce,
+                    payment_amount,
+                )
+                .call(self.minter_contract.mint_token_with_options(
+                    name,
+                    media,
+                    medatada,
+                    data_marshal,
+                    data_stream,
+                    data_preview,
+                    managed_biguint!(royalties),
+                    managed_biguint!(supply),
+                    title,
+                    description,
+                    lock_period,
+                    donation_percentage,
+                    options,
+                    MultiValueEncoded::new(),
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_add_referrers(
+        &mut self,
+        caller: &str,
+        referrers: Vec<Address>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        let mut args = MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+        for referrer in referrers {
+            args.push(managed_address!(&referrer));
+        }
+
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.add_referrers(args))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_register_promo_code(
+        &mut self,
+        caller: &str,
+        code_hash: &[u8; 32],
+        discount: PromoDiscount<StaticApi>,
+        max_uses: u64,
+        expires_at: u64,
+        max_uses_per_address: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.register_promo_code(
+                    ManagedByteArray::<StaticApi, 32>::new_from_bytes(code_hash),
+                    discount,
+                    max_uses,
+                    expires_at,
+                    max_uses_per_address,
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_remove_promo_code(
+        &mut self,
+        caller: &str,
+        code_hash: &[u8; 32],
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.remove_promo_code(
+                    ManagedByteArray::<StaticApi, 32>::new_from_bytes(code_hash),
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_set_referral_share(
+        &mut self,
+        caller: &str,
+        basis_points: u64,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.set_referral_share(basis_points))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+        self
+    }
+
+    pub fn minter_set_local_roles(&mut self, caller: &str, expect: Option<TxExpect>) -> &mut Self {
+        let tx_expect = expect.unwrap_or(TxExpect::ok());
+
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .call(self.minter_contract.set_local_roles())
+                .expect(tx_expect),
+        );
+        self
+    }
+
+    pub fn minter_initialize_contract(
+        &mut self,
+        caller: &str,
+        collection_name: &str,
+        token_ticker: &str,
+        anti_spam_tax_token: &[u8],
+        anti_spam_tax_value: u64,
+        mint_time_limit: u64,
+        treasury_address: Address,
+        amount: Option<u64>,
+        expect: Option<TxExpect>,
+    ) -> &mut Self {
+        self.world.sc_call(
+            ScCallStep::new()
+                .from(caller)
+                .egld_value(amount.unwrap_or(50000000000000000))
+                .call(self.minter_contract.initialize_contract(
+                    collection_name,
+                    token_ticker,
+                    managed_token_id_wrapped!(anti_spam_tax_token),
+                    managed_biguint!(anti_spam_tax_value),
+                    mint_time_limit,
+                    treasury_address,
+                ))
+                .expect(expect.unwrap_or(TxExpect::ok())),
+        );
+
+        self
+    }
+
+    pub fn mock_minter_initialized(
+        &mut self,
+        anti_spam_tax_token: &[u8],
+        anti_spam_tax_value: u64,
+        mint_time_limit: u64,
+    ) -> &mut Self {
+        let admin = self.admin.clone();
+        let treasury_address = self.treasury.clone();
+        let minter_code = self.world.code_expression(MINTER_CONTRACT_PATH);
+
+        let mut acc = Account::new()
             .esdt_roles(
                 DATA_NFT_IDENTIFIER_EXPR,
                 vec![
@@ -669,6 +1488,10 @@ impl ContractsState {
                     "ESDTRoleNFTBurn".to_string(),
                 ],
             )
+            .esdt_roles(
+                MINT_PASS_TOKEN_IDENTIFIER_EXPR,
+                vec!["ESDTRoleLocalBurn".to_string()],
+            )
             .code(minter_code);
 
         acc.storage.insert(
@@ -697,7 +1520,7 @@ impl ContractsState {
             treasury_address,
             None,
         );
-        self.minter_set_anti_spam_tax_token_and_amount(
+        self.minter_add_accepted_payment_token(
             MINTER_OWNER_ADDRESS_EXPR,
             anti_spam_tax_token,
             anti_spam_tax_value,
@@ -859,4 +1682,30 @@ impl ContractsState {
 
         self
     }
+
+    // egld-esdt-swap stand-in holding the EGLD locked for the wrapped tokens in circulation
+    pub fn egld_wrapper_default_set(&mut self, locked_egld: &str) -> &mut Self {
+        let egld_wrapper_code = self.world.code_expression(EGLD_WRAPPER_CONTRACT_PATH);
+
+        let mut acc = Account::new()
+            .balance(locked_egld)
+            .esdt_roles(
+                WEGLD_TOKEN_IDENTIFIER_EXPR,
+                vec![
+                    "ESDTRoleLocalMint".to_string(),
+                    "ESDTRoleLocalBurn".to_string(),
+                ],
+            )
+            .code(egld_wrapper_code);
+
+        acc.storage.insert(
+            b"wrappedEgldTokenId".to_vec().into(),
+            WEGLD_TOKEN_IDENTIFIER.to_vec().into(),
+        );
+
+        self.world.set_state_step(
+            SetStateStep::new().put_account(EGLD_WRAPPER_CONTRACT_ADDRESS_EXPR, acc),
+        );
+        self
+    }
 }
@@ -1,7 +1,16 @@
-use datanftmint::{requirements::RequirementsModule, storage::StorageModule as _, DataNftMint};
-use multiversx_sc::{storage::mappers::StorageTokenWrapper as _, types::BigUint};
+use datanftmint::{
+    errors::MinterError,
+    requirements::RequirementsModule,
+    storage::{AuditAction, StorageModule as _},
+    views::ViewsModule,
+    DataNftMint,
+};
+use multiversx_sc::{
+    storage::mappers::StorageTokenWrapper as _,
+    types::{BigUint, OptionalValue},
+};
 use multiversx_sc_scenario::{
-    api::SingleTxApi, managed_address, managed_buffer, managed_token_id,
+    api::SingleTxApi, managed_address, managed_buffer, managed_token_id, managed_token_id_wrapped,
     scenario_model::AddressValue,
 };
 
@@ -514,3 +523,141 @@ fn require_is_withdrawal_address_test() {
 
     assert_eq!(result.is_ok(), true);
 }
+
+#[test]
+fn get_contract_config_test() {
+    let minter_contract = datanftmint::contract_obj::<SingleTxApi>();
+
+    minter_contract.init();
+
+    let config = minter_contract.get_contract_config();
+
+    assert_eq!(config.token_id.is_none(), true);
+    assert_eq!(config.treasury_address.is_none(), true);
+    assert_eq!(config.anti_spam_taxes.len(), 0);
+    assert_eq!(config.max_supply, BigUint::from(20u64));
+    assert_eq!(config.max_royalties, BigUint::from(8000u64));
+    assert_eq!(config.is_paused, true);
+    assert_eq!(config.whitelist_enabled, true);
+    assert_eq!(config.roles_are_set, false);
+    assert_eq!(config.config_version, 4u64);
+
+    minter_contract
+        .token_id()
+        .set_token_id(managed_token_id!(ITHEUM_TOKEN_IDENTIFIER));
+
+    minter_contract
+        .treasury_address()
+        .set(managed_address!(
+            &AddressValue::from("address:treasury").to_address()
+        ));
+
+    minter_contract
+        .anti_spam_tax(&managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER))
+        .set(BigUint::from(100u64));
+
+    minter_contract
+        .accepted_payment_tokens()
+        .insert(managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER));
+
+    let config = minter_contract.get_contract_config();
+
+    assert_eq!(
+        config.token_id,
+        Some(managed_token_id!(ITHEUM_TOKEN_IDENTIFIER))
+    );
+    assert_eq!(
+        config.treasury_address,
+        Some(managed_address!(
+            &AddressValue::from("address:treasury").to_address()
+        ))
+    );
+    assert_eq!(config.anti_spam_taxes.len(), 1);
+    assert_eq!(config.anti_spam_taxes.get(0).amount, BigUint::from(100u64));
+}
+
+#[test]
+fn error_catalog_test() {
+    for (index, error) in MinterError::ALL.iter().enumerate() {
+        let prefix = format!("E{}: ", error.code());
+        assert_eq!(error.message().starts_with(&prefix), true);
+
+        for other in MinterError::ALL[index + 1..].iter() {
+            assert_ne!(error.code(), other.code());
+        }
+    }
+
+    assert_eq!(
+        MinterError::UrlTooSmall.message(),
+        "E1007: URL length is too small"
+    );
+}
+
+#[test]
+fn upgrade_registers_anti_spam_tax_token_test() {
+    let minter_contract = datanftmint::contract_obj::<SingleTxApi>();
+
+    minter_contract.init();
+
+    // state of a contract deployed before the accepted payment tokens registry
+    minter_contract
+        .anti_spam_tax(&managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER))
+        .set(BigUint::from(100u64));
+
+    minter_contract.upgrade(OptionalValue::Some(managed_token_id_wrapped!(
+        ITHEUM_TOKEN_IDENTIFIER
+    )));
+
+    assert_eq!(
+        minter_contract
+            .accepted_payment_tokens()
+            .contains(&managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER)),
+        true
+    );
+    let config = minter_contract.get_contract_config();
+    assert_eq!(config.anti_spam_taxes.len(), 1);
+    assert_eq!(config.anti_spam_taxes.get(0).amount, BigUint::from(100u64));
+
+    minter_contract
+        .require_payment_token_is_accepted(&managed_token_id_wrapped!(ITHEUM_TOKEN_IDENTIFIER));
+}
+
+#[test]
+fn audit_log_test() {
+    let minter_contract = datanftmint::contract_obj::<SingleTxApi>();
+
+    for supply in 1u64..=5u64 {
+        minter_contract.append_audit_entry(
+            AuditAction::SetMaxSupply,
+            &BigUint::<SingleTxApi>::from(supply),
+        );
+    }
+
+    let entries = minter_contract
+        .get_audit_log(0u64, 10u64)
+        .into_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(entries.len(), 5);
+    assert_eq!(entries[0].id, 0u64);
+    assert_eq!(entries[0].action, AuditAction::SetMaxSupply);
+    assert_ne!(entries[0].params_hash, entries[1].params_hash);
+
+    let page = minter_contract
+        .get_audit_log(3u64, 1u64)
+        .into_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].id, 3u64);
+
+    minter_contract.audit_log_retention().set(2u64);
+    minter_contract.append_audit_entry(AuditAction::SetIsPaused, &true);
+
+    let entries = minter_contract
+        .get_audit_log(0u64, 10u64)
+        .into_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].id, 4u64);
+    assert_eq!(entries[1].action, AuditAction::SetIsPaused);
+    assert_eq!(minter_contract.audit_log_entry(0u64).is_empty(), true);
+}
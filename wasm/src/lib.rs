@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           52
+// Endpoints:                          138
 // Async Callback:                       1
-// Total number of exported functions:  54
+// Total number of exported functions: 140
 
 #![no_std]
 #![allow(internal_features)]
@@ -24,35 +24,71 @@ multiversx_sc_wasm_adapter::endpoints! {
         initializeContract => initialize_contract
         setLocalRoles => set_local_roles
         mint => mint_token
+        mintWithOptions => mint_token_with_options
         burn => burn_token
         setTreasuryAddress => set_treasury_address
+        setTreasuryDistribution => set_treasury_distribution
+        setTreasuryAccumulationEnabled => set_treasury_accumulation_enabled
+        claimTreasury => claim_treasury
         setDonationTreasuryAddress => set_donation_treasury_address
         setMaxDonationPercentage => set_max_donation_percentage
         setIsPaused => set_is_paused
         setWhiteListEnabled => set_whitelist_enabled
         setAntiSpamTax => set_anti_spam_tax
+        addAcceptedPaymentToken => add_accepted_payment_token
+        removeAcceptedPaymentToken => remove_accepted_payment_token
+        setAntiSpamTaxPerUnit => set_anti_spam_tax_per_unit
+        setLockPeriodTaxMultiplier => set_lock_period_tax_multiplier
+        setSurgePricing => set_surge_pricing
+        setGlobalMintLimit => set_global_mint_limit
+        addRateLimitExemptions => add_rate_limit_exemptions
+        removeRateLimitExemptions => remove_rate_limit_exemptions
         setWhiteListSpots => set_whitelist_spots
         removeWhiteListSpots => remove_whitelist_spots
+        addReferrers => add_referrers
+        removeReferrers => remove_referrers
+        setReferralShare => set_referral_share
+        registerPromoCode => register_promo_code
+        removePromoCode => remove_promo_code
         setMintTimeLimit => set_mint_time_limit
+        setFreeMintsPerAddress => set_free_mints_per_address
         setRoyaltiesLimits => set_royalties_limits
         setMaxSupply => set_max_supply
         setAdministrator => set_administrator
         setBondContractAddress => set_bond_contract_address
+        setBondPaymentToken => set_bond_payment_token
+        setEgldWrapper => set_egld_wrapper
         setWithdrawalAddress => set_withdrawal_address
+        setAuditLogRetention => set_audit_log_retention
         withdraw => withdraw
+        withdrawMany => withdraw_many
         getTokenId => token_id
         getTreasuryAddress => treasury_address
+        getTreasuryDistribution => treasury_distribution
+        isTreasuryAccumulationEnabled => treasury_accumulation_enabled
+        getTreasuryClaimableAmount => treasury_claimable
+        getCreatorRoyaltyShare => creator_royalty_share
+        getCreatorRoyaltiesAmount => creator_royalties
+        getReservedFunds => reserved_funds
         getDonationTreasuryAddress => donation_treasury_address
         getMaxDonationPercentage => max_donation_percentage
         getWithdrawalAddress => withdrawal_address
         getMintedTokens => minted_tokens
+        getConfigVersion => config_version
+        getDataNftSupply => data_nft_supply
+        getDataNftCreator => data_nft_creator
         getAntiSpamTax => anti_spam_tax
+        getAntiSpamTaxPerUnit => anti_spam_tax_per_unit
+        getSurgePricing => surge_pricing
+        getGlobalMintLimit => global_mint_limit
+        getRateLimitExemptAddresses => rate_limit_exempt_addresses
         getIsPaused => is_paused
         getMaxRoyalties => max_royalties
         getMinRoyalties => min_royalties
         getMaxSupply => max_supply
         getMintedPerAddress => minted_per_address
         mintTimeLimit => mint_time_limit
+        getFreeMintsPerAddress => free_mints_per_address
         lastMintTime => last_mint_time
         getWhiteList => whitelist
         getCollectionFrozenList => frozen_addresses_for_collection
@@ -62,7 +98,37 @@ multiversx_sc_wasm_adapter::endpoints! {
         rolesAreSet => roles_are_set
         getAdministrator => administrator
         getBondContractAddress => bond_contract_address
+        getBondPaymentToken => bond_payment_token
+        getWrappedEgldTokenId => wrapped_egld_token_id
+        getEgldWrapperAddress => egld_wrapper_address
+        getReferrers => referrers
+        getReferralShare => referral_share
+        getReferredMints => referred_mints
+        getReferralRewardsAmount => referral_rewards
+        getPromoCode => promo_code
+        getPromoCodeUses => promo_code_uses
+        getSponsorToken => sponsor_token
+        getSponsorBalance => sponsor_balance
+        getSponsorConsumedAmount => sponsor_consumed
+        getSponsoredCreators => sponsored_creators
+        getCreatorSponsor => creator_sponsor
+        getSponsoredMintLimit => sponsored_mint_limit
+        getSponsoredMints => sponsored_mints
+        getCreditBalance => credit_balance
+        getCreditTokens => credit_tokens
+        getAuditLogRetention => audit_log_retention
         getUserDataOut => get_user_data_out
+        getAcceptedPaymentTokens => get_accepted_payment_tokens
+        getMintQuote => get_mint_quote
+        validateMint => validate_mint
+        getContractConfig => get_contract_config
+        getErrorCatalog => get_error_catalog
+        getTreasuryClaimable => get_treasury_claimable
+        getReferrerStats => get_referrer_stats
+        getDiscountedTax => get_discounted_tax_view
+        getComputedAntiSpamTax => get_computed_anti_spam_tax
+        getLockPeriodTaxMultipliers => get_lock_period_tax_multipliers
+        getAuditLog => get_audit_log
         pause => pause_collection
         unpause => unpause_collection
         freeze => freeze_collection_for_address
@@ -70,8 +136,28 @@ multiversx_sc_wasm_adapter::endpoints! {
         freezeSingleNFT => freeze_single_token_for_address
         unFreezeSingleNFT => unfreeze_single_token_for_address
         wipeSingleNFT => wipe_single_token_for_address
-        get_bond_amount_for_lock_period => get_bond_amount_for_lock_period
-        send_bond => send_bond
+        depositRoyalties => deposit_royalties
+        claimRoyalties => claim_royalties
+        setCreatorRoyaltyShare => set_creator_royalty_share
+        getCreatorRoyalties => get_creator_royalties
+        registerRescueClaim => register_rescue_claim
+        cancelRescueClaim => cancel_rescue_claim
+        rescueTokens => rescue_tokens
+        getOpenRescueClaims => get_open_rescue_claims
+        depositSponsorFunds => deposit_sponsor_funds
+        withdrawSponsorFunds => withdraw_sponsor_funds
+        addSponsoredCreators => add_sponsored_creators
+        acceptSponsor => accept_sponsor
+        removeSponsoredCreators => remove_sponsored_creators
+        getSponsorInfo => get_sponsor_info
+        getSponsorshipOffers => get_sponsorship_offers
+        depositCredits => deposit_credits
+        withdrawCredits => withdraw_credits
+        getCredits => get_credits
+        setMintPassToken => set_mint_pass_token
+        depositMintPasses => deposit_mint_passes
+        distributeMintPasses => distribute_mint_passes
+        getMintPassConfig => get_mint_pass_config
     )
 }
 